@@ -0,0 +1,173 @@
+use anyhow::{anyhow, Context, Result};
+use serde_json::{Map, Value};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Trait enum generated from one js-templates.json table. `keys` is the variant order and the order
+// of the enum's ALL, it has no say in which card gets which trait: that is decided by the pools in
+// js_generator.rs. Variant names are the keys in CamelCase.
+struct TraitEnum {
+  name: &'static str,
+  comment: &'static str,
+  category: &'static str,
+  accessor: &'static str,
+  keys: &'static [&'static str],
+}
+
+const TRAIT_ENUMS: &[TraitEnum] = &[
+  TraitEnum {
+    name: "Background",
+    comment: "",
+    category: "background",
+    accessor: "css",
+    keys: &[
+      "mystical_purple", "cosmic_blue", "golden_mystic", "rose_gold", "dark_void", "emerald_green",
+      "blood_red", "neon_pink", "cyber_yellow", "arctic_aqua", "lava_orange", "abyss_blue", "toxic_lime",
+      "ethereal_white", "obsidian_black", "ultraviolet",
+    ],
+  },
+  TraitEnum {
+    name: "MainSymbol",
+    comment: "Main symbol of the card, mystical symbols are drawn from the same set",
+    category: "mainSymbol",
+    accessor: "glyph",
+    keys: &[
+      "star", "moon", "sun", "tower", "wheel", "hermit", "magician", "priestess", "emperor", "empress",
+      "devil", "fool", "hierophant", "lovers", "chariot", "strength", "justice", "hanged_man", "death",
+      "temperance", "judgement", "world", "balloon", "flask", "puppet", "taco", "acai", "diesel", "clock",
+      "chick", "fartane", "arbuz", "genesis",
+    ],
+  },
+  TraitEnum {
+    name: "CardTitle",
+    comment: "",
+    category: "cardTitles",
+    accessor: "names",
+    keys: &[
+      "the_star", "the_moon", "the_sun", "the_tower", "the_wheel", "the_hermit", "the_magician",
+      "the_priestess", "the_emperor", "the_empress", "the_devil", "the_fool", "the_hierophant",
+      "the_lovers", "the_chariot", "strength", "justice", "the_hanged_man", "death", "temperance",
+      "judgement", "the_world", "airhead_card", "mist_card", "puppet_card", "taco_card", "acai_card",
+      "diesel_card", "clockin_card", "cheekyb_card", "fartane_card", "arbuz_card", "genesis",
+    ],
+  },
+  TraitEnum {
+    name: "BorderColor",
+    comment: "",
+    category: "borderColors",
+    accessor: "css",
+    keys: &["gold", "silver", "bronze", "purple", "blue", "red", "green"],
+  },
+  TraitEnum {
+    name: "GlowColor",
+    comment: "",
+    category: "glowColors",
+    accessor: "css",
+    keys: &["gold", "silver", "purple", "blue", "green", "red"],
+  },
+];
+
+// the_hanged_man -> TheHangedMan
+fn variant_name(key: &str) -> String {
+  key
+    .split('_')
+    .map(|word| {
+      let mut chars = word.chars();
+      match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+      }
+    })
+    .collect()
+}
+
+fn string_value(category: &str, key: &str, value: &Value) -> Result<String> {
+  value
+    .as_str()
+    .map(|s| s.to_string())
+    .ok_or_else(|| anyhow!("js-templates.json: {}.{} must be a string", category, key))
+}
+
+// Card titles are either one name or an [english, chinese] pair
+fn title_value(key: &str, value: &Value) -> Result<(String, String)> {
+  match value {
+    Value::String(title) => Ok((title.clone(), title.clone())),
+    Value::Array(pair) if pair.len() == 2 => Ok((
+      string_value("cardTitles", key, &pair[0])?,
+      string_value("cardTitles", key, &pair[1])?,
+    )),
+    _ => Err(anyhow!("js-templates.json: cardTitles.{} must be a string or an [english, chinese] pair", key)),
+  }
+}
+
+fn generate_enum(trait_enum: &TraitEnum, table: &Map<String, Value>) -> Result<String> {
+  let TraitEnum { name, comment, category, accessor, keys } = trait_enum;
+
+  // Every key needs an entry and every entry needs a key, so the table and the enum can't drift apart
+  for (i, key) in keys.iter().enumerate() {
+    if keys[..i].contains(key) {
+      return Err(anyhow!("build.rs: {} lists {} twice", name, key));
+    }
+    if !key.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
+      return Err(anyhow!("build.rs: {} key {} must be snake_case", name, key));
+    }
+  }
+  for key in table.keys() {
+    if !keys.contains(&key.as_str()) {
+      return Err(anyhow!("js-templates.json: {}.{} is not in the {} key list in build.rs", category, key, name));
+    }
+  }
+
+  let mut generated = String::from("\ntrait_enum!(\n");
+  if !comment.is_empty() {
+    generated.push_str(&format!("  // {}\n", comment));
+  }
+  generated.push_str(&format!("  {} {{\n", name));
+  for key in *keys {
+    generated.push_str(&format!("    {} => {:?},\n", variant_name(key), key));
+  }
+  generated.push_str("  }\n);\n");
+
+  let return_type = if *category == "cardTitles" { "(&'static str, &'static str)" } else { "&'static str" };
+  generated.push_str(&format!("\nimpl {} {{\n  pub fn {}(&self) -> {} {{\n    match self {{\n", name, accessor, return_type));
+  for key in *keys {
+    let value = table
+      .get(*key)
+      .ok_or_else(|| anyhow!("js-templates.json: {} has no entry for {}", category, key))?;
+    let literal = if *category == "cardTitles" {
+      let (title, title_cn) = title_value(key, value)?;
+      format!("({:?}, {:?})", title, title_cn)
+    } else {
+      format!("{:?}", string_value(category, key, value)?)
+    };
+    generated.push_str(&format!("      {}::{} => {},\n", name, variant_name(key), literal));
+  }
+  generated.push_str("    }\n  }\n}\n");
+
+  Ok(generated)
+}
+
+fn main() -> Result<()> {
+  println!("cargo:rerun-if-changed=src/js-templates.json");
+  println!("cargo:rerun-if-changed=build.rs");
+
+  let manifest_dir = env::var("CARGO_MANIFEST_DIR")?;
+  let templates_path = Path::new(&manifest_dir).join("src/js-templates.json");
+
+  let templates: Value = serde_json::from_str(&fs::read_to_string(&templates_path)?)
+    .context("js-templates.json is not valid JSON")?;
+
+  let mut generated = String::from("// Generated by build.rs from src/js-templates.json and the key lists in build.rs\n");
+  for trait_enum in TRAIT_ENUMS {
+    let table = templates[trait_enum.category]
+      .as_object()
+      .ok_or_else(|| anyhow!("js-templates.json: missing {} table", trait_enum.category))?;
+    generated.push_str(&generate_enum(trait_enum, table)?);
+  }
+
+  let out_dir = env::var("OUT_DIR")?;
+  fs::write(Path::new(&out_dir).join("js_templates.rs"), generated)?;
+
+  Ok(())
+}
@@ -0,0 +1,106 @@
+use anyhow::{anyhow, Error};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+// Declares a trait enum whose string form is the key used in js-templates.json
+macro_rules! trait_enum {
+  ($(#[$meta:meta])* $name:ident { $($variant:ident => $key:literal),+ $(,)? }) => {
+    $(#[$meta])*
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum $name {
+      $(#[serde(rename = $key)] $variant),+
+    }
+
+    impl $name {
+      pub const ALL: &'static [$name] = &[$($name::$variant),+];
+
+      pub fn as_str(&self) -> &'static str {
+        match self {
+          $($name::$variant => $key),+
+        }
+      }
+    }
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = Error;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+          $($key => Ok($name::$variant),)+
+          _ => Err(anyhow!("unknown {} {}", stringify!($name), s)),
+        }
+      }
+    }
+  };
+}
+
+trait_enum!(
+  // Rarity tier, decided by the card hash before any other trait
+  CardTier {
+    Genesis => "genesis",
+    Classic => "classic",
+    Glitch => "glitch",
+    Absolute => "absolute",
+  }
+);
+
+impl CardTier {
+  // Higher is rarer
+  pub fn rarity_rank(&self) -> u8 {
+    match self {
+      CardTier::Classic => 1,
+      CardTier::Glitch => 2,
+      CardTier::Absolute => 3,
+      CardTier::Genesis => 4,
+    }
+  }
+}
+
+impl CardTitle {
+  // Glitch and absolute titles have a single name, so the card never switches its title to Chinese
+  pub fn is_special(&self) -> bool {
+    matches!(
+      self,
+      CardTitle::AirheadCard | CardTitle::MistCard | CardTitle::PuppetCard | CardTitle::TacoCard
+        | CardTitle::AcaiCard | CardTitle::DieselCard | CardTitle::ClockinCard | CardTitle::CheekybCard
+        | CardTitle::FartaneCard | CardTitle::ArbuzCard
+    )
+  }
+}
+
+// Everything `JsGenerator::decode_traits` derives for a single card
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardTraits {
+  pub tier: CardTier,
+  pub background: Background,
+  pub main_symbol: MainSymbol,
+  pub mystical_symbols: [MainSymbol; 3],
+  pub card_title: CardTitle,
+  pub border_color: BorderColor,
+  pub glow_color: GlowColor,
+}
+
+impl fmt::Display for CardTraits {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} ({}): {} background, {} with {}/{}/{}, {} border, {} glow",
+      self.card_title, self.tier, self.background, self.main_symbol,
+      self.mystical_symbols[0], self.mystical_symbols[1], self.mystical_symbols[2],
+      self.border_color, self.glow_color
+    )
+  }
+}
+
+// Background, MainSymbol, CardTitle, BorderColor and GlowColor, generated by build.rs from
+// js-templates.json with their template values: Background::css, MainSymbol::glyph,
+// CardTitle::names, BorderColor::css and GlowColor::css
+include!(concat!(env!("OUT_DIR"), "/js_templates.rs"));
@@ -0,0 +1,67 @@
+use anyhow::{anyhow, Result};
+
+// Clock-in answers opcode 103 with one of:
+//   no data            - only accepted with `accept_empty`, set by SetClockin for older clock-in
+//                        contracts where the call succeeding is the approval
+//   1 byte             - bool, non-zero approves
+//   16 bytes           - u128 flag, non-zero approves
+//   32 bytes           - inclusive (start, end) block window of two u128 that must hold `height`
+// Anything else is rejected rather than guessed at.
+pub fn verdict(data: &[u8], height: u128, accept_empty: bool) -> Result<bool> {
+  match data.len() {
+    0 if accept_empty => Ok(true),
+    0 => Err(anyhow!("Clock-in answered without a verdict")),
+    1 => Ok(data[0] != 0),
+    16 => Ok(u128::from_le_bytes(data.try_into().unwrap()) != 0),
+    32 => {
+      let start = u128::from_le_bytes(data[..16].try_into().unwrap());
+      let end = u128::from_le_bytes(data[16..].try_into().unwrap());
+      Ok(start <= height && height <= end)
+    }
+    len => Err(anyhow!("Unexpected clock-in response of {} bytes", len)),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn window(start: u128, end: u128) -> Vec<u8> {
+    [start.to_le_bytes(), end.to_le_bytes()].concat()
+  }
+
+  #[test]
+  fn empty_response_only_approves_in_legacy_mode() {
+    assert!(verdict(&[], 100, false).is_err());
+    assert!(verdict(&[], 100, true).unwrap());
+  }
+
+  #[test]
+  fn flags_approve_when_non_zero() {
+    for accept_empty in [false, true] {
+      assert!(!verdict(&[0], 100, accept_empty).unwrap());
+      assert!(verdict(&[1], 100, accept_empty).unwrap());
+      assert!(verdict(&[0xff], 100, accept_empty).unwrap());
+      assert!(!verdict(&0u128.to_le_bytes(), 100, accept_empty).unwrap());
+      assert!(verdict(&1u128.to_le_bytes(), 100, accept_empty).unwrap());
+      assert!(verdict(&(1u128 << 127).to_le_bytes(), 100, accept_empty).unwrap());
+    }
+  }
+
+  #[test]
+  fn window_is_inclusive() {
+    assert!(!verdict(&window(100, 200), 99, false).unwrap());
+    assert!(verdict(&window(100, 200), 100, false).unwrap());
+    assert!(verdict(&window(100, 200), 150, false).unwrap());
+    assert!(verdict(&window(100, 200), 200, false).unwrap());
+    assert!(!verdict(&window(100, 200), 201, false).unwrap());
+    assert!(!verdict(&window(200, 100), 150, false).unwrap());
+  }
+
+  #[test]
+  fn other_lengths_are_rejected() {
+    for len in [2, 8, 15, 17, 31, 33, 64] {
+      assert!(verdict(&vec![1; len], 100, true).is_err(), "{} bytes", len);
+    }
+  }
+}
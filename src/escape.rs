@@ -0,0 +1,227 @@
+// Escaping for every context the generators splice strings into. Card titles, predictions and
+// template values all pass through here before they reach generated JS, HTML or SVG.
+
+// Body of a single or double quoted JS string literal. `<` is escaped as well so the
+// literal can never close the <script> element it is embedded in.
+pub fn js_string(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    match c {
+      '\\' => escaped.push_str("\\\\"),
+      '\'' => escaped.push_str("\\'"),
+      '"' => escaped.push_str("\\\""),
+      '`' => escaped.push_str("\\x60"),
+      '\n' => escaped.push_str("\\n"),
+      '\r' => escaped.push_str("\\r"),
+      '\t' => escaped.push_str("\\t"),
+      '<' => escaped.push_str("\\x3C"),
+      '>' => escaped.push_str("\\x3E"),
+      '\u{2028}' => escaped.push_str("\\u2028"),
+      '\u{2029}' => escaped.push_str("\\u2029"),
+      c if (c as u32) < 0x20 => escaped.push_str(&format!("\\x{:02X}", c as u32)),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+// HTML or XML text and quoted attribute values
+pub fn html(value: &str) -> String {
+  let mut escaped = String::with_capacity(value.len());
+  for c in value.chars() {
+    match c {
+      '&' => escaped.push_str("&amp;"),
+      '<' => escaped.push_str("&lt;"),
+      '>' => escaped.push_str("&gt;"),
+      '"' => escaped.push_str("&quot;"),
+      '\'' => escaped.push_str("&#39;"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+// Markup text inside a JS template literal that is later assigned to innerHTML:
+// HTML-escaped first, then made inert for the template literal itself
+pub fn template_html(value: &str) -> String {
+  let html = html(value);
+  let mut escaped = String::with_capacity(html.len());
+  for c in html.chars() {
+    match c {
+      '\\' => escaped.push_str("\\\\"),
+      '`' => escaped.push_str("\\`"),
+      '$' => escaped.push_str("\\$"),
+      _ => escaped.push(c),
+    }
+  }
+  escaped
+}
+
+// CSS colors and gradients only need this character set, anything else is dropped so a
+// value can't end the declaration, the rule, the template literal or the <style> element
+pub fn css_value(value: &str) -> String {
+  value
+    .chars()
+    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, ' ' | '#' | '%' | '(' | ')' | ',' | '.' | '-'))
+    .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+
+  // Everything that can end or open a JS string, template literal, <script> block, tag or XML attribute
+  pub(crate) const ADVERSARIAL: &[&str] = &[
+    "'",
+    "\"",
+    "`",
+    "${",
+    "</script>",
+    "<",
+    "&",
+    "\\",
+    "\n",
+    "\u{2028}",
+    "it's a \"`${trap}`\" </script><script>alert(1)</script> <!-- & \\ \n\u{2028}",
+  ];
+
+  // Tag names and attribute names of a document in order, quoted attribute values and text left out.
+  // A value that gets out of its attribute or text node changes the outline.
+  pub(crate) fn markup_outline(markup: &str) -> String {
+    let mut outline = String::new();
+    let mut quote: Option<char> = None;
+    let mut in_tag = false;
+    for c in markup.chars() {
+      match (in_tag, quote) {
+        (true, Some(q)) => {
+          if c == q {
+            quote = None;
+            outline.push(c);
+          }
+        }
+        (true, None) => {
+          outline.push(c);
+          match c {
+            '"' | '\'' => quote = Some(c),
+            '>' => in_tag = false,
+            _ => {}
+          }
+        }
+        (false, _) => {
+          if c == '<' {
+            in_tag = true;
+            outline.push(c);
+          }
+        }
+      }
+    }
+    outline
+  }
+
+  fn unescape_js(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+      if c != '\\' {
+        unescaped.push(c);
+        continue;
+      }
+      match chars.next().unwrap() {
+        'n' => unescaped.push('\n'),
+        'r' => unescaped.push('\r'),
+        't' => unescaped.push('\t'),
+        'x' => {
+          let code: String = chars.by_ref().take(2).collect();
+          unescaped.push(char::from_u32(u32::from_str_radix(&code, 16).unwrap()).unwrap());
+        }
+        'u' => {
+          let code: String = chars.by_ref().take(4).collect();
+          unescaped.push(char::from_u32(u32::from_str_radix(&code, 16).unwrap()).unwrap());
+        }
+        other => unescaped.push(other),
+      }
+    }
+    unescaped
+  }
+
+  fn unescape_html(value: &str) -> String {
+    value
+      .replace("&lt;", "<")
+      .replace("&gt;", ">")
+      .replace("&quot;", "\"")
+      .replace("&#39;", "'")
+      .replace("&amp;", "&")
+  }
+
+  #[test]
+  fn js_string_stays_inside_its_literal() {
+    for value in ADVERSARIAL {
+      let escaped = js_string(value);
+      let mut chars = escaped.chars();
+      while let Some(c) = chars.next() {
+        match c {
+          '\\' => {
+            chars.next();
+          }
+          '\'' | '"' | '`' | '\n' | '\r' | '\u{2028}' | '\u{2029}' | '<' => {
+            panic!("{:?} escaped to {:?}, raw {:?} left in the literal", value, escaped, c)
+          }
+          _ => {}
+        }
+      }
+      assert_eq!(unescape_js(&escaped), *value);
+    }
+  }
+
+  #[test]
+  fn html_leaves_no_markup() {
+    for value in ADVERSARIAL {
+      let escaped = html(value);
+      assert!(!escaped.contains(['<', '>', '"', '\'']), "{:?} escaped to {:?}", value, escaped);
+      for (i, _) in escaped.match_indices('&') {
+        let entity = &escaped[i..];
+        assert!(
+          ["&amp;", "&lt;", "&gt;", "&quot;", "&#39;"].iter().any(|known| entity.starts_with(known)),
+          "{:?} escaped to {:?}, bare & left", value, escaped
+        );
+      }
+      assert_eq!(unescape_html(&escaped), *value);
+    }
+  }
+
+  #[test]
+  fn template_html_stays_inside_its_template_literal() {
+    for value in ADVERSARIAL {
+      let escaped = template_html(value);
+      assert!(!escaped.contains('<'), "{:?} escaped to {:?}", value, escaped);
+      let mut chars = escaped.chars().peekable();
+      while let Some(c) = chars.next() {
+        match c {
+          '\\' => {
+            chars.next();
+          }
+          '`' => panic!("{:?} escaped to {:?}, raw backtick left", value, escaped),
+          '$' if chars.peek() == Some(&'{') => panic!("{:?} escaped to {:?}, raw ${{ left", value, escaped),
+          _ => {}
+        }
+      }
+      assert_eq!(unescape_html(&unescape_js(&escaped)), *value);
+    }
+  }
+
+  #[test]
+  fn css_value_keeps_only_color_characters() {
+    let attacks = ADVERSARIAL.iter().copied()
+      .chain(["red; } </style><script>alert(1)</script>", "url('javascript:alert(1)')", "#fff\\;color:red"]);
+    for value in attacks {
+      let sanitized = css_value(value);
+      assert!(
+        sanitized.chars().all(|c| c.is_ascii_alphanumeric() || " #%(),.-".contains(c)),
+        "{:?} sanitized to {:?}", value, sanitized
+      );
+    }
+    for color in ["linear-gradient(135deg, #2d1b69 0%, #5b21b6 50%, #2d1b69 100%)", "rgba(255, 215, 0, 0.3)", "#c0c0c0"] {
+      assert_eq!(css_value(color), color);
+    }
+  }
+}
@@ -1,18 +1,183 @@
+use crate::card_traits::{
+  Background, BorderColor, CardTier, CardTitle, CardTraits, GlowColor, MainSymbol
+};
+use crate::escape;
 use crate::predict_generator::generate_prediction;
 use crate::roman_numerals::to_roman;
-use serde_json::{Value, json};
-use anyhow::Result;
+use crate::svg_generator::SvgGenerator;
+use crate::template;
+use serde_json::{json, Value};
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use sha2::{Sha256, Digest};
 
-const JS_TEMPLATES_JSON: &str = include_str!("js-templates.json");
+// Local stacks used when the card must not reach out to Google Fonts
+const SYSTEM_LATIN_FONTS: &str = "'Cormorant Garamond', 'EB Garamond', Garamond, Georgia, 'Times New Roman', serif";
+pub(crate) const SYSTEM_CJK_FONTS: &str = "'Noto Serif SC', 'Source Han Serif SC', 'Songti SC', STSong, SimSun, serif";
 
-pub struct JsGenerator;
+// Where the card's typefaces come from. Web fonts import Cormorant Garamond and Noto Serif SC
+// from Google Fonts, system fonts keep the card working offline and in sandboxed viewers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FontMode {
+  #[default]
+  WebFonts,
+  SystemFonts,
+}
 
-impl JsGenerator {
-  fn get_js_templates() -> Value {
-    serde_json::from_str(JS_TEMPLATES_JSON).unwrap()
+impl FontMode {
+  pub fn from_code(code: u128) -> Result<FontMode> {
+    match code {
+      0 => Ok(FontMode::WebFonts),
+      1 => Ok(FontMode::SystemFonts),
+      _ => Err(anyhow!("Unknown font mode {}", code)),
+    }
+  }
+
+  pub fn code(&self) -> u128 {
+    match self {
+      FontMode::WebFonts => 0,
+      FontMode::SystemFonts => 1,
+    }
+  }
+
+  // (@import rule, Latin font stack, Chinese font stack)
+  fn font_values(&self) -> (&'static str, &'static str, &'static str) {
+    match self {
+      FontMode::WebFonts => (template::FONT_IMPORT_CSS, "'Cormorant Garamond', serif", "'Noto Serif SC', serif"),
+      FontMode::SystemFonts => ("", SYSTEM_LATIN_FONTS, SYSTEM_CJK_FONTS),
+    }
+  }
+}
+
+// Language the card text is rendered in. Single-language cards keep the click toggle to the
+// other language, both puts the Chinese beneath the English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+  #[default]
+  English,
+  Chinese,
+  Both,
+}
+
+impl Language {
+  pub fn from_code(code: u128) -> Result<Language> {
+    match code {
+      0 => Ok(Language::English),
+      1 => Ok(Language::Chinese),
+      2 => Ok(Language::Both),
+      _ => Err(anyhow!("Unknown language {}", code)),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Language::English => "en",
+      Language::Chinese => "zh",
+      Language::Both => "both",
+    }
+  }
+}
+
+// Tier reported for minted cards until the collection is revealed, it is a state rather than a CardTier
+pub const UNREVEALED_TIER: &str = "unrevealed";
+const UNREVEALED_SYMBOL: &str = "🂠";
+
+// What the card views draw, the template values of decoded traits or of the unrevealed placeholder
+#[derive(Debug, Clone)]
+pub(crate) struct CardFace {
+  pub(crate) background: &'static str,
+  pub(crate) main_symbol: &'static str,
+  pub(crate) mystical_symbols: [&'static str; 3],
+  pub(crate) card_title: &'static str,
+  pub(crate) card_title_cn: &'static str,
+  pub(crate) is_special_card: bool,
+  pub(crate) border_color: &'static str,
+  pub(crate) glow_color: &'static str,
+}
+
+impl CardFace {
+  pub(crate) fn from_traits(traits: &CardTraits) -> CardFace {
+    let (card_title, card_title_cn) = traits.card_title.names();
+    CardFace {
+      background: traits.background.css(),
+      main_symbol: traits.main_symbol.glyph(),
+      mystical_symbols: traits.mystical_symbols.map(|symbol| symbol.glyph()),
+      card_title,
+      card_title_cn,
+      is_special_card: traits.card_title.is_special(),
+      border_color: traits.border_color.css(),
+      glow_color: traits.glow_color.css(),
+    }
   }
 
+  // Title shown in `language`, with the Chinese title drawn beneath it when both are shown.
+  // Special titles have no translation, they stay in English whatever the language.
+  pub(crate) fn title_for(&self, language: Language) -> (&'static str, Option<&'static str>) {
+    match language {
+      Language::Chinese if !self.is_special_card => (self.card_title_cn, None),
+      Language::Both if !self.is_special_card => (self.card_title, Some(self.card_title_cn)),
+      _ => (self.card_title, None),
+    }
+  }
+
+  // Face down card served for minted orbitals until the collection is revealed
+  pub(crate) fn unrevealed() -> CardFace {
+    CardFace {
+      background: Background::ObsidianBlack.css(),
+      main_symbol: UNREVEALED_SYMBOL,
+      mystical_symbols: [UNREVEALED_SYMBOL; 3],
+      card_title: "UNREVEALED",
+      card_title_cn: "未揭晓",
+      is_special_card: false,
+      border_color: BorderColor::Silver.css(),
+      glow_color: GlowColor::Silver.css(),
+    }
+  }
+}
+
+// Trait pools in the order card hashes index into them, these decide what every card gets. They are
+// spelled out rather than taken from the enums' ALL, which follows the key lists in build.rs, so
+// editing a key list can't reshuffle minted cards.
+const BACKGROUNDS: [Background; 16] = [
+  Background::MysticalPurple, Background::CosmicBlue, Background::GoldenMystic, Background::RoseGold,
+  Background::DarkVoid, Background::EmeraldGreen, Background::BloodRed, Background::NeonPink,
+  Background::CyberYellow, Background::ArcticAqua, Background::LavaOrange, Background::AbyssBlue,
+  Background::ToxicLime, Background::EtherealWhite, Background::ObsidianBlack, Background::Ultraviolet,
+];
+const BORDER_COLORS: [BorderColor; 7] = [
+  BorderColor::Gold, BorderColor::Silver, BorderColor::Bronze, BorderColor::Purple,
+  BorderColor::Blue, BorderColor::Red, BorderColor::Green,
+];
+const GLOW_COLORS: [GlowColor; 6] = [
+  GlowColor::Gold, GlowColor::Silver, GlowColor::Purple, GlowColor::Blue, GlowColor::Green, GlowColor::Red,
+];
+const CLASSIC_MAIN_SYMBOLS: [MainSymbol; 22] = [
+  MainSymbol::Star, MainSymbol::Moon, MainSymbol::Sun, MainSymbol::Tower, MainSymbol::Wheel,
+  MainSymbol::Hermit, MainSymbol::Magician, MainSymbol::Priestess, MainSymbol::Emperor, MainSymbol::Empress,
+  MainSymbol::Devil, MainSymbol::Fool, MainSymbol::Hierophant, MainSymbol::Lovers, MainSymbol::Chariot,
+  MainSymbol::Strength, MainSymbol::Justice, MainSymbol::HangedMan, MainSymbol::Death, MainSymbol::Temperance,
+  MainSymbol::Judgement, MainSymbol::World,
+];
+const CLASSIC_CARD_TITLES: [CardTitle; 22] = [
+  CardTitle::TheStar, CardTitle::TheMoon, CardTitle::TheSun, CardTitle::TheTower, CardTitle::TheWheel,
+  CardTitle::TheHermit, CardTitle::TheMagician, CardTitle::ThePriestess, CardTitle::TheEmperor, CardTitle::TheEmpress,
+  CardTitle::TheDevil, CardTitle::TheFool, CardTitle::TheHierophant, CardTitle::TheLovers, CardTitle::TheChariot,
+  CardTitle::Strength, CardTitle::Justice, CardTitle::TheHangedMan, CardTitle::Death, CardTitle::Temperance,
+  CardTitle::Judgement, CardTitle::TheWorld,
+];
+const GLITCH_MAIN_SYMBOLS: [MainSymbol; 8] = [
+  MainSymbol::Balloon, MainSymbol::Flask, MainSymbol::Puppet, MainSymbol::Taco,
+  MainSymbol::Acai, MainSymbol::Diesel, MainSymbol::Clock, MainSymbol::Chick,
+];
+const GLITCH_CARD_TITLES: [CardTitle; 8] = [
+  CardTitle::AirheadCard, CardTitle::MistCard, CardTitle::PuppetCard, CardTitle::TacoCard,
+  CardTitle::AcaiCard, CardTitle::DieselCard, CardTitle::ClockinCard, CardTitle::CheekybCard,
+];
+const ABSOLUTE_MAIN_SYMBOLS: [MainSymbol; 2] = [MainSymbol::Fartane, MainSymbol::Arbuz];
+
+pub struct JsGenerator;
+
+impl JsGenerator {
   // Helper function to get mystical code based on card type
   fn get_mystical_code(encoded: u64, mystical_bits: u64, background_bits: u64, card_bits: u64, is_absolute: bool, is_glitch: bool, mystical_index: u64) -> usize {
     if is_absolute {
@@ -46,65 +211,65 @@ impl JsGenerator {
   }
 
   // Helper function to get mystical symbols array
-  fn get_mystical_symbols(mystical1_code: usize, mystical2_code: usize, mystical3_code: usize, symbols: &[&'static str]) -> Vec<&'static str> {
+  fn get_mystical_symbols(mystical1_code: usize, mystical2_code: usize, mystical3_code: usize, symbols: &[MainSymbol]) -> [MainSymbol; 3] {
     let m1 = symbols[mystical1_code % symbols.len()];
     let m2 = symbols[mystical2_code % symbols.len()];
     let m3 = symbols[mystical3_code % symbols.len()];
-    vec![m1, m2, m3]
+    [m1, m2, m3]
   } 
 
-  // Helper function to get template value with fallback
-  fn get_template_value(templates: &Value, category: &str, key: &str, fallback: &str) -> String {
-    templates[category][key].as_str().unwrap_or(fallback).to_string()
+  // Card number as printed on the card
+  pub(crate) fn index_display(index: u128) -> String {
+    if index == 0 {
+        "GENESIS".to_string()
+    } else {
+        to_roman(index)
+    }
   }
 
-  // Helper function to get card title values
-  fn get_card_title_values(templates: &Value, card_title: &str) -> (String, String) {
-    if let Some(title_array) = templates["cardTitles"][card_title].as_array() {
-      (
-        title_array[0].as_str().unwrap_or("").to_string(),
-        title_array[1].as_str().unwrap_or("").to_string()
-      )
+  // Display name, the genesis card's title already is its number
+  fn card_name(card_title_value: &str, index_display: &str) -> String {
+    if card_title_value == index_display {
+      card_title_value.to_string()
     } else {
-      let title_str = templates["cardTitles"][card_title].as_str().unwrap_or("").to_string();
-      (title_str.clone(), title_str)
+      format!("{} {}", card_title_value, index_display)
     }
   }
 
-  // Helper function to check if card is special
-  fn is_special_card(card_title: &str) -> bool {
-    const SPECIAL_CARDS: [&str; 10] = [
-      "airhead_card", "mist_card", "puppet_card", "taco_card", 
-      "acai_card", "diesel_card", "clockin_card", "cheekyb_card", 
-      "fartane_card", "arbuz_card"
-    ];
-    SPECIAL_CARDS.contains(&card_title)
+  pub(crate) fn unrevealed_prediction() -> (String, String) {
+    (
+      "THE CARDS ARE STILL BEING SHUFFLED".to_string(),
+      "牌仍在洗".to_string()
+    )
   }
 
-  pub fn decode_traits(index: u128) -> Result<(String, String, Vec<&'static str>, String, String, String)> {
+  // Traits are derived from the index mixed with the entropy captured when the card was minted
+  pub fn decode_traits(index: u128, entropy: &[u8]) -> Result<CardTraits> {
     // Special case for index 0 - GENESIS card
     if index == 0 {
-      return Ok((
-        "ethereal_white".to_string(),
-        "genesis".to_string(),
-        vec!["genesis", "genesis", "genesis"],
-        "genesis".to_string(),
-        "gold".to_string(),
-        "gold".to_string()
-      ));
+      return Ok(CardTraits {
+        tier: CardTier::Genesis,
+        background: Background::EtherealWhite,
+        main_symbol: MainSymbol::Genesis,
+        mystical_symbols: [MainSymbol::Genesis, MainSymbol::Genesis, MainSymbol::Genesis],
+        card_title: CardTitle::Genesis,
+        border_color: BorderColor::Gold,
+        glow_color: GlowColor::Gold,
+      });
     }
     
-    let backgrounds = vec!["mystical_purple", "cosmic_blue", "golden_mystic", "rose_gold", "dark_void", "emerald_green", "blood_red", "neon_pink", "cyber_yellow", "arctic_aqua", "lava_orange", "abyss_blue", "toxic_lime", "ethereal_white", "obsidian_black", "ultraviolet"];
-    let border_colors = vec!["gold", "silver", "bronze", "purple", "blue", "red", "green"];
-    let glow_colors = vec!["gold", "silver", "purple", "blue", "green", "red"];
-    let classic_main_symbols = vec!["star", "moon", "sun", "tower", "wheel", "hermit", "magician", "priestess", "emperor", "empress", "devil", "fool", "hierophant", "lovers", "chariot", "strength", "justice", "hanged_man", "death", "temperance", "judgement", "world"];
-    let classic_card_titles = vec!["the_star", "the_moon", "the_sun", "the_tower", "the_wheel", "the_hermit", "the_magician", "the_priestess", "the_emperor", "the_empress", "the_devil", "the_fool", "the_hierophant", "the_lovers", "the_chariot", "strength", "justice", "the_hanged_man", "death", "temperance", "judgement", "the_world"];
-    let glitch_main_symbols = vec!["balloon", "flask", "puppet", "taco", "acai", "diesel", "clock", "chick"];
-    let glitch_card_titles = vec!["airhead_card", "mist_card", "puppet_card", "taco_card", "acai_card", "diesel_card", "clockin_card", "cheekyb_card"];
-    let absolute_main_symbol = vec!["fartane", "arbuz"];
+    let backgrounds = BACKGROUNDS;
+    let border_colors = BORDER_COLORS;
+    let glow_colors = GLOW_COLORS;
+    let classic_main_symbols = CLASSIC_MAIN_SYMBOLS;
+    let classic_card_titles = CLASSIC_CARD_TITLES;
+    let glitch_main_symbols = GLITCH_MAIN_SYMBOLS;
+    let glitch_card_titles = GLITCH_CARD_TITLES;
+    let absolute_main_symbol = ABSOLUTE_MAIN_SYMBOLS;
     
     let mut hasher = Sha256::new();
     hasher.update(index.to_le_bytes());
+    hasher.update(entropy);
     let hash = hasher.finalize();
     
     let encoded = u64::from_le_bytes(hash[0..8].try_into().unwrap());
@@ -128,17 +293,17 @@ impl JsGenerator {
       let absolute_card_code = (encoded & 1) as usize;
       if absolute_card_code == 0 {
         (
-          "fartane",
-          "fartane_card", 
-          "silver",
-          "silver"
+          MainSymbol::Fartane,
+          CardTitle::FartaneCard,
+          BorderColor::Silver,
+          GlowColor::Silver
         )
       } else {
         (
-          "arbuz",
-          "arbuz_card",
-          "green",
-          "green"
+          MainSymbol::Arbuz,
+          CardTitle::ArbuzCard,
+          BorderColor::Green,
+          GlowColor::Green
         )
       }
     } else if is_glitch {
@@ -146,8 +311,8 @@ impl JsGenerator {
       (
         glitch_main_symbols[glitch_card_code % glitch_main_symbols.len()],
         glitch_card_titles[glitch_card_code % glitch_card_titles.len()],
-        "gold",
-        "gold"
+        BorderColor::Gold,
+        GlowColor::Gold
       )
     } else {
       let card_code = ((encoded >> BACKGROUND_BITS) & ((1u64 << CLASSIC_CARD_BITS) - 1)) as usize;
@@ -178,161 +343,408 @@ impl JsGenerator {
     let background = if is_absolute {
       let absolute_card_code = (encoded & 1) as usize;
       if absolute_card_code == 0 {
-        "lava_orange"
+        Background::LavaOrange
       } else {
-        "blood_red"
+        Background::BloodRed
       }
     } else {
       backgrounds[background_code % backgrounds.len()]
     };
-    Ok((
-      background.to_string(),
-      main_symbol.to_string(),
-      mystical_symbols_array,
-      card_title.to_string(),
-      border_color.to_string(),
-      glow_color.to_string()
-    ))
+
+    let tier = if is_absolute {
+      CardTier::Absolute
+    } else if is_glitch {
+      CardTier::Glitch
+    } else {
+      CardTier::Classic
+    };
+
+    Ok(CardTraits {
+      tier,
+      background,
+      main_symbol,
+      mystical_symbols: mystical_symbols_array,
+      card_title,
+      border_color,
+      glow_color,
+    })
   }
 
-  pub fn get_attributes(index: u128) -> Result<String> {
-    let (background, main_symbol, mystical_symbols_array, card_title, border_color, glow_color) = Self::decode_traits(index)?;
-    let (prediction, _prediction_cn) = crate::predict_generator::generate_prediction(index);
+  pub fn get_attributes(index: u128, entropy: &[u8]) -> Result<String> {
+    Self::render_attributes(index, Self::decode_traits(index, entropy)?, generate_prediction(index, entropy))
+  }
 
-    let js_templates = Self::get_js_templates();
+  // Only what is public before the reveal: the card number, the unrevealed state and the placeholder prediction
+  pub fn get_unrevealed_attributes(index: u128) -> Result<String> {
+    let (prediction, _prediction_cn) = Self::unrevealed_prediction();
 
     let attributes = json!({
-      "background": background,
-      "mainSymbol": Self::get_template_value(&js_templates, "mainSymbol", &main_symbol, "💩"),
-      "mysticalSymbols": mystical_symbols_array.iter()
-        .map(|symbol| Self::get_template_value(&js_templates, "mainSymbol", symbol, "💩"))
-        .collect::<Vec<String>>()
+      "cardNumberIndex": index.to_string(),
+      "tier": UNREVEALED_TIER,
+      "prediction": prediction
+    });
+
+    Ok(attributes.to_string())
+  }
+
+  fn render_attributes(index: u128, traits: CardTraits, predictions: (String, String)) -> Result<String> {
+    let (prediction, _prediction_cn) = predictions;
+
+
+    let attributes = json!({
+      "background": traits.background.as_str(),
+      "mainSymbol": traits.main_symbol.glyph(),
+      "mysticalSymbols": traits.mystical_symbols.iter()
+        .map(|symbol| symbol.glyph())
+        .collect::<Vec<&str>>()
         .join(","),
-      "cardTitle": card_title,
+      "cardTitle": traits.card_title.as_str(),
       "cardNumberIndex": index.to_string(),
-      "borderColor": border_color,
-      "glowColor": glow_color,
+      "borderColor": traits.border_color.as_str(),
+      "glowColor": traits.glow_color.as_str(),
+      "tier": traits.tier.as_str(),
+      "rarityRank": traits.tier.rarity_rank(),
       "prediction": prediction
     });
 
     Ok(attributes.to_string())
   }
 
-  pub fn generate_js(index: u128) -> Result<String> {
-    let (background, main_symbol, mystical_symbols_array, card_title, border_color, glow_color) = Self::decode_traits(index)?;
-    let (prediction_eng, prediction_cn) = generate_prediction(index);
+  pub fn get_metadata(index: u128, entropy: &[u8], fonts: FontMode) -> Result<String> {
+    let traits = Self::decode_traits(index, entropy)?;
+    let predictions = generate_prediction(index, entropy);
+    let index_display = Self::index_display(index);
+    let (card_title_value, _card_title_cn_value) = traits.card_title.names();
 
-    let index_display = if index == 0 {
-        "GENESIS".to_string()
-    } else {
-        to_roman(index)
-    };
+    let attributes = json!([
+      { "trait_type": "Tier", "value": traits.tier.as_str() },
+      { "trait_type": "Rarity Rank", "value": traits.tier.rarity_rank() },
+      { "trait_type": "Card Title", "value": card_title_value },
+      { "trait_type": "Background", "value": traits.background.as_str() },
+      { "trait_type": "Main Symbol", "value": traits.main_symbol.glyph() },
+      { "trait_type": "Mystical Symbols", "value": traits.mystical_symbols.iter()
+        .map(|symbol| symbol.glyph())
+        .collect::<Vec<&str>>()
+        .join(",") },
+      { "trait_type": "Border Color", "value": traits.border_color.as_str() },
+      { "trait_type": "Glow Color", "value": traits.glow_color.as_str() },
+      { "trait_type": "Card Number", "value": index_display },
+      { "trait_type": "Prediction", "value": predictions.0 }
+    ]);
 
-    let js_templates = Self::get_js_templates();
+    Self::render_metadata(index, &CardFace::from_traits(&traits), predictions, fonts, attributes)
+  }
 
-    // Get template values using helper functions
-    let background_value = Self::get_template_value(&js_templates, "background", &background, "linear-gradient(135deg,rgb(255, 255, 255) 0%,rgb(255, 255, 255) 50%,rgb(255, 255, 255) 100%)");
-    let main_symbol_value = Self::get_template_value(&js_templates, "mainSymbol", &main_symbol, "");
-    let border_color_value = Self::get_template_value(&js_templates, "borderColors", &border_color, "#ffffff");
-    let glow_color_value = Self::get_template_value(&js_templates, "glowColors", &glow_color, "transparent");
+  pub fn get_unrevealed_metadata(index: u128, fonts: FontMode) -> Result<String> {
+    let predictions = Self::unrevealed_prediction();
+
+    let attributes = json!([
+      { "trait_type": "Tier", "value": UNREVEALED_TIER },
+      { "trait_type": "Card Number", "value": Self::index_display(index) },
+      { "trait_type": "Prediction", "value": predictions.0 }
+    ]);
+
+    Self::render_metadata(index, &CardFace::unrevealed(), predictions, fonts, attributes)
+  }
+
+  // Marketplace metadata shape: name, description, image, animation_url and a list of trait_type/value attributes.
+  // Metadata is always English, localized views are served by GetLocalizedImage and GetLocalizedHtml
+  fn render_metadata(index: u128, face: &CardFace, predictions: (String, String), fonts: FontMode, attributes: Value) -> Result<String> {
+    let index_display = Self::index_display(index);
+    let name = Self::card_name(face.card_title, &index_display);
+
+    // Static SVG for wallets and image proxies, the interactive card goes in animation_url
+    let svg = SvgGenerator::render_svg(index, face, predictions.clone(), Language::English)?;
+    let image = format!("data:image/svg+xml;base64,{}", BASE64.encode(svg));
+
+    let document = Self::render_html(index, face, predictions.clone(), fonts, Language::English)?;
+    let animation_url = format!("data:text/html;base64,{}", BASE64.encode(document));
+
+    let metadata = json!({
+      "name": name,
+      "description": format!("Magic Arbuz Collection card #{}. {}", index, predictions.0),
+      "image": image,
+      "animation_url": animation_url,
+      "attributes": attributes
+    });
+
+    Ok(metadata.to_string())
+  }
+
+  pub fn generate_html(index: u128, entropy: &[u8], fonts: FontMode, language: Language) -> Result<String> {
+    let face = CardFace::from_traits(&Self::decode_traits(index, entropy)?);
+    Self::render_html(index, &face, generate_prediction(index, entropy), fonts, language)
+  }
+
+  pub fn generate_unrevealed_html(index: u128, fonts: FontMode, language: Language) -> Result<String> {
+    Self::render_html(index, &CardFace::unrevealed(), Self::unrevealed_prediction(), fonts, language)
+  }
+
+  // Complete page around the card script, the script's DOMContentLoaded hook draws into the container
+  pub(crate) fn render_html(index: u128, face: &CardFace, predictions: (String, String), fonts: FontMode, language: Language) -> Result<String> {
+    let index_display = Self::index_display(index);
+    let name = Self::card_name(face.card_title, &index_display);
+
+    let js = Self::render_js(index, face, predictions, fonts, language)?;
+
+    let html = template::render(template::CARD_HTML, &[
+      ("title", &escape::html(&name)),
+      ("script", &js),
+    ])?;
+
+    Ok(html)
+  }
+
+  pub fn generate_js(index: u128, entropy: &[u8], fonts: FontMode, language: Language) -> Result<String> {
+    let face = CardFace::from_traits(&Self::decode_traits(index, entropy)?);
+    Self::render_js(index, &face, generate_prediction(index, entropy), fonts, language)
+  }
+
+  pub fn generate_unrevealed_js(index: u128, fonts: FontMode, language: Language) -> Result<String> {
+    Self::render_js(index, &CardFace::unrevealed(), Self::unrevealed_prediction(), fonts, language)
+  }
+
+  // English text with its Chinese counterpart stacked beneath, for the html template
+  fn bilingual_html(text: &str, text_cn: &str) -> Result<String> {
+    template::render(template::BILINGUAL_TEXT_HTML, &[
+      ("text", &escape::template_html(text)),
+      ("text_cn", &escape::template_html(text_cn)),
+    ])
+  }
+
+  pub(crate) fn render_js(index: u128, face: &CardFace, predictions: (String, String), fonts: FontMode, language: Language) -> Result<String> {
+    let (prediction_eng, prediction_cn) = predictions;
+
+    let index_display = Self::index_display(index);
+
+    // Template values come from the tables build.rs generates, CSS values are sanitized before they reach the styles
+    let background_value = escape::css_value(face.background);
+    let main_symbol_value = face.main_symbol;
+    let border_color_value = escape::css_value(face.border_color);
+    let glow_color_value = escape::css_value(face.glow_color);
     
-    let is_special_card = Self::is_special_card(&card_title);
-    let (card_title_value, card_title_cn_value) = Self::get_card_title_values(&js_templates, &card_title);
-
-    let mut js = String::from("function createMagicArbuzCard(containerId) {\n  const container = document.getElementById(containerId);\n  if (!container) {\n    console.error('Container with id ' + containerId + ' not found');\n    return;\n  }\n\n  const cardData = {\n    title: '");
-    js.push_str(&card_title_value);
-    js.push_str("',\n    title_cn: '");
-    js.push_str(&card_title_cn_value);
-    js.push_str("',\n    isSpecialCard: ");
-    js.push_str(&is_special_card.to_string());
-    js.push_str(",\n    subtitle: '");
-    js.push_str(&index_display);
-    js.push_str("',\n    message_eng: '");
-    js.push_str(&prediction_eng);
-    js.push_str("',\n    message_cn: '");
-    js.push_str(&prediction_cn);
-    js.push_str("',\n    description: ''\n  };\n\n  const styles = `\n    <style>\n      @import url('https://fonts.googleapis.com/css2?family=Cormorant+Garamond:ital,wght@0,300;0,400;0,500;0,600;0,700;1,300;1,400;1,500;1,600;1,700&family=Noto+Serif+SC:wght@400;500;600;700&display=swap');\n      @keyframes twinkle {\n        0% { opacity: 0.7; transform: scale(1); filter: drop-shadow(0 0 10px ");
-      js.push_str(&glow_color_value);
-      js.push_str("); }\n        50% { opacity: 1; transform: scale(1.05); filter: drop-shadow(0 0 20px ");
-      js.push_str(&glow_color_value);
-      js.push_str("); }\n        100% { opacity: 0.8; transform: scale(1.02); filter: drop-shadow(0 0 15px ");
-      js.push_str(&glow_color_value);
-      js.push_str("); }\n      }\n      @keyframes sparkle {\n        0% { opacity: 0.3; transform: scale(0.8); filter: drop-shadow(0 0 5px rgba(199, 210, 254, 0.4)); }\n        50% { opacity: 1; transform: scale(1.2); filter: drop-shadow(0 0 10px rgba(199, 210, 254, 0.8)); }\n        100% { opacity: 0.5; transform: scale(0.9); filter: drop-shadow(0 0 7px rgba(199, 210, 254, 0.6)); }\n      }\n      @keyframes glow {\n        0% { opacity: 0.6; transform: scale(1); filter: drop-shadow(0 0 5px currentColor); }\n        50% { opacity: 1; transform: scale(1.04); filter: drop-shadow(0 0 15px currentColor); }\n        100% { opacity: 0.7; transform: scale(1.02); filter: drop-shadow(0 0 10px currentColor); }\n      }\n      @keyframes cardGlow {\n        0% { box-shadow: inset 0 0 20px ");
-            js.push_str(&glow_color_value);
-      js.push_str(", 0 0 30px ");
-      js.push_str(&glow_color_value);
-      js.push_str(", 0 0 15px ");
-      js.push_str(&border_color_value);
-      js.push_str("; border-color: ");
-      js.push_str(&border_color_value);
-      js.push_str("; }\n        50% { box-shadow: inset 0 0 30px ");
-      js.push_str(&glow_color_value);
-      js.push_str(", 0 0 50px ");
-      js.push_str(&glow_color_value);
-      js.push_str(", 0 0 25px ");
-      js.push_str(&border_color_value);
-      js.push_str("; border-color: ");
-      js.push_str(&border_color_value);
-      js.push_str("; }\n        100% { box-shadow: inset 0 0 25px ");
-      js.push_str(&glow_color_value);
-      js.push_str(", 0 0 40px ");
-      js.push_str(&glow_color_value);
-      js.push_str(", 0 0 20px ");
-      js.push_str(&border_color_value);
-      js.push_str("; border-color: ");
-      js.push_str(&border_color_value);
-      js.push_str("; }\n      }\n      @keyframes backgroundShimmer {\n        0% { opacity: 0.3; background: radial-gradient(circle at 30% 30%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%); }\n        25% { opacity: 0.6; background: radial-gradient(circle at 70% 40%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%); }\n        50% { opacity: 0.8; background: radial-gradient(circle at 50% 70%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%); }\n        75% { opacity: 0.4; background: radial-gradient(circle at 20% 60%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%); }\n        100% { opacity: 0.5; background: radial-gradient(circle at 80% 20%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%); }\n      }\n      .magic-arbuz-card-wrapper {\n        display: flex;\n        justify-content: center;\n        align-items: center;\n        perspective: 1000px;\n        min-height: 500px;\n      }\n      .magic-arbuz-card {\n        width: 400px;\n        height: 650px;\n        position: relative;\n        cursor: pointer;\n        transform-style: preserve-3d;\n        transition: all 0.1s ease-out;\n        transform: rotateY(0deg) rotateX(0deg) scale(1);\n        filter: drop-shadow(0 20px 40px rgba(0,0,0,0.5));\n        box-sizing: border-box;\n      }\n      .magic-arbuz-card-front {\n        position: absolute;\n        width: 100%;\n        height: 100%;\n        background: ");
-    js.push_str(&background_value);
-    js.push_str(";\n        border-radius: 12px;\n        border: 3px solid ");
-    js.push_str(&border_color_value);
-    js.push_str(";\n        box-shadow: inset 0 0 20px ");
-    js.push_str(&glow_color_value);
-    js.push_str(", 0 0 30px ");
-    js.push_str(&glow_color_value);
-    js.push_str(";\n        animation: cardGlow 4s ease-in-out infinite alternate;\n        display: flex;\n        flex-direction: column;\n        justify-content: space-between;\n        padding: 25px;\n        backface-visibility: hidden;\n        position: relative;\n        overflow: hidden;\n        box-sizing: border-box;\n      }\n      .mystical-background {\n        position: absolute;\n        top: 0;\n        left: 0;\n        right: 0;\n        bottom: 0;\n        background: radial-gradient(circle at 50% 50%, ");
-      js.push_str(&glow_color_value);
-      js.push_str(" 0%, transparent 70%);\n        opacity: 0.5;\n        animation: backgroundShimmer 5s ease-in-out infinite alternate;\n      }\n      .card-number {\n        text-align: center;\n        margin-bottom: 20px;\n      }\n      .card-number-text {\n        font-size: 28px;\n        font-weight: 700;\n        color: #ffd700;\n        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 1px 4px rgba(30,30,30,0.18),0 0 10px ");
-      js.push_str(&glow_color_value);
-      js.push_str(";\n        font-family: serif;\n        letter-spacing: 2px;\n        animation: glow 3s ease-in-out infinite alternate;\n        animation-delay: 0.5s;\n        margin: 0;\n      }\n      .card-title-text {\n        font-size: 20px;\n        font-weight: 600;\n        color: #e0e7ff;\n        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 0 8px rgba(224,231,255,0.5);\n        font-family: serif;\n        letter-spacing: 1px;\n        margin-top: 5px;\n        animation: glow 3.5s ease-in-out infinite alternate;\n        animation-delay: 0.8s;\n        margin-bottom: 0;\n        min-height: 30px;\n        display: flex;\n        align-items: center;\n        justify-content: center;\n      }\n      .central-illustration {\n        display: flex;\n        flex-direction: column;\n        align-items: center;\n        justify-content: center;\n        flex: 1;\n        position: relative;\n      }\n      .main-symbol {\n        font-size: 80px;\n        color: ");
-    js.push_str(&border_color_value);
-    js.push_str(";\n        text-shadow: 0 0 20px ");
-    js.push_str(&glow_color_value);
-    js.push_str(";\n        animation: twinkle 4s ease-in-out infinite alternate;\n        z-index: 2;\n        position: relative;\n      }\n      .mystical-symbols {\n        display: flex;\n        justify-content: center;\n        gap: 10px;\n        margin-bottom: 10px;\n        margin-top: 0;\n      }\n      .mystical-symbol {\n        font-size: 32px;\n        text-shadow: 0 0 8px #a78bfa;\n        animation: glow 2.5s ease-in-out infinite alternate;\n      }\n      .small-stars-orbit{position:absolute;left:50%;top:50%;transform:translate(-50%,-50%);width:120px;height:120px;pointer-events:none;}.small-stars{font-size:24px;color:#c7d2fe;animation:sparkle 3s ease-in-out infinite alternate;z-index:1;pointer-events:none;}\n      .card-message {\n        text-align: center;\n        margin-top: 20px;\n      }\n      .card-message-text {\n        font-size: 20px;\n        font-weight: 700;\n        color: #cbd5e1;\n        font-style: normal;\n        line-height: 1.3;\n        font-family: 'Cormorant Garamond', serif;\n        letter-spacing: 1px;\n        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 1px 4px rgba(30,30,30,0.18),0 0 5px rgba(203, 213, 225, 0.3);\n        animation: glow 4s ease-in-out infinite alternate;\n        animation-delay: 1.2s;\n        margin: 0;\n        margin-bottom: 30px;\n        padding: 0 15px;\n        min-height: 60px;\n        height: 80px;\n        display: flex;\n        align-items: center;\n        justify-content: center;\n        text-align: center;\n        overflow: hidden;\n      }\n      .magic-arbuz-card:hover {\n        transform: rotateY(5deg) rotateX(5deg) scale(1.02);\n        filter: drop-shadow(0 25px 50px rgba(0,0,0,0.6));\n      }\n      .magic-arbuz-card:active {\n        transform: rotateY(10deg) rotateX(10deg) scale(0.98);\n      }\n    </style>\n  `;\n\n  const html = `\n    <div class=\"magic-arbuz-card-wrapper\">\n      <div class=\"magic-arbuz-card\">\n        <div class=\"magic-arbuz-card-front\">\n          <div class=\"magic-arbuz-card-border-decoration\" style=\"pointer-events:none;position:absolute;top:0;left:0;width:100%;height:100%;z-index:1;\"><svg viewBox='0 0 400 650' width='100%' height='100%' fill='none' xmlns='http://www.w3.org/2000/svg' style='display:block;'><rect x='14' y='12' width='372' height='626' rx='12' stroke='#ffe066' stroke-width='2.5' opacity='0.35'/></svg></div>\n          <div class=\"mystical-background\"></div>\n          <div class=\"card-number\">\n            <div class=\"card-number-text\">");
-    js.push_str(&index_display);
-    js.push_str("</div>\n            <div class=\"card-title-text\">");
-    js.push_str(&card_title_value);
-    js.push_str("</div>\n          </div>\n          <div class=\"central-illustration\">\n            <div class=\"main-star-container\" style=\"position:relative;margin-bottom:20px;\">\n              <div class=\"main-star\" style=\"font-size:140px;color:#ffd700;text-shadow:0 0 20px rgba(255,215,0,0.8);position:relative;z-index:2;filter:drop-shadow(0 0 10px rgba(255,215,0,0.5));animation:twinkle 2s ease-in-out infinite alternate;\">");
-    js.push_str(&main_symbol_value);
-    js.push_str("</div>");
-    js.push_str("<div class=\"small-star small-star-1\" style=\"position:absolute;top:-20px;left:-30px;font-size:30px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.2s;animation-duration:1.5s;\">✦</div>");
-    js.push_str("<div class=\"small-star small-star-2\" style=\"position:absolute;top:-15px;right:-25px;font-size:25px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.5s;animation-duration:1.8s;\">✦</div>");
-    js.push_str("<div class=\"small-star small-star-3\" style=\"position:absolute;bottom:-20px;left:-20px;font-size:20px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.8s;animation-duration:2.2s;\">✦</div>");
-    js.push_str("<div class=\"small-star small-star-4\" style=\"position:absolute;bottom:-15px;right:-30px;font-size:35px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:1.1s;animation-duration:1.3s;\">✦</div>");
-    js.push_str("</div>\n");
-    js.push_str("<div class=\"mystical-symbols\" style=\"display:flex;justify-content:center;gap:10px;margin-bottom:10px;margin-top:0;\">");
-    for symbol in mystical_symbols_array {
-      let mystical_symbol_value = js_templates["mainSymbol"][symbol].as_str().unwrap_or("💩");
-      js.push_str("<div class=\"mystical-symbol\">");
-      js.push_str(&mystical_symbol_value);
-      js.push_str("</div>");
+    let is_special_card = face.is_special_card;
+    let (card_title_value, card_title_cn_value) = (face.card_title, face.card_title_cn);
+    let (font_import, latin_font, cjk_font) = fonts.font_values();
+
+    let card_title_text = match face.title_for(language) {
+      (title, Some(title_cn)) => Self::bilingual_html(title, title_cn)?,
+      (title, None) => escape::template_html(title),
+    };
+    let message_text = match language {
+      Language::English => escape::template_html(&prediction_eng),
+      Language::Chinese => escape::template_html(&prediction_cn),
+      Language::Both => Self::bilingual_html(&prediction_eng, &prediction_cn)?,
+    };
+
+    let mut mystical_symbols = String::new();
+    for mystical_symbol_value in face.mystical_symbols {
+      mystical_symbols.push_str(&template::render(template::MYSTICAL_SYMBOL_HTML, &[
+        ("symbol", &escape::template_html(mystical_symbol_value)),
+      ])?);
     }
-    js.push_str("</div>\n");
-    js.push_str("</div>\n          <div class=\"card-message\">\n            <div class=\"card-message-text\">");
-    js.push_str(&prediction_eng);
-    js.push_str("</div>\n          </div>\n");
 
-    js.push_str("\n        </div>\n      </div>\n    </div>\n  `;\n\n  container.innerHTML = styles + html;\n\n  const magicArbuzCard = container.querySelector('.magic-arbuz-card');\n  if (magicArbuzCard) {\n    let lastRotateX = 0;\n    let lastRotateY = 0;\n    magicArbuzCard.addEventListener('mousemove', function(e) {\n      const rect = magicArbuzCard.getBoundingClientRect();\n      const x = e.clientX - rect.left;\n      const y = e.clientY - rect.top;\n      const centerX = rect.width / 2;\n      const centerY = rect.height / 2;\n      const deltaX = x - centerX; const deltaY = y - centerY; const distance = Math.sqrt(deltaX * deltaX + deltaY * deltaY); const maxDistance = Math.sqrt(centerX * centerX + centerY * centerY); const normalizedDistance = Math.min(distance / maxDistance, 1); const intensity = Math.pow(normalizedDistance, 0.6) * 1.2 + 0.4;\n      let rotateX = (deltaY / centerY) * 15 * intensity;\n      let rotateY = (-deltaX / centerX) * 15 * intensity;\n      const maxAngle = 15;\n      rotateX = Math.max(-maxAngle, Math.min(maxAngle, rotateX));\n      rotateY = Math.max(-maxAngle, Math.min(maxAngle, rotateY));\n      const smoothFactor = 0.15;\n      rotateX = lastRotateX + (rotateX - lastRotateX) * smoothFactor;\n      rotateY = lastRotateY + (rotateY - lastRotateY) * smoothFactor;\n      lastRotateX = rotateX; lastRotateY = rotateY;\n      magicArbuzCard.style.transform = 'rotateY(' + rotateY + 'deg) rotateX(' + rotateX + 'deg) scale(1.05)';\n      magicArbuzCard.style.filter = 'drop-shadow(0 30px 60px rgba(0,0,0,0.6))';\n    });\n    magicArbuzCard.addEventListener('mouseleave', function() {\n      lastRotateX = 0; lastRotateY = 0;\n      magicArbuzCard.style.transform = 'rotateY(0deg) rotateX(0deg) scale(1)';\n      magicArbuzCard.style.filter = 'drop-shadow(0 20px 40px rgba(0,0,0,0.5))';\n    });\n  }\n  \n  const messageDiv = container.querySelector('.card-message-text');\n  const titleDiv = container.querySelector('.card-title-text');\n  let currentLang = 'eng';\n  let clickStartTime = 0;\n  let isLongPress = false;\n  let longPressTimer = null;\n  \n  if (magicArbuzCard && messageDiv && titleDiv) {\n    magicArbuzCard.addEventListener('mousedown', function() {\n      clickStartTime = Date.now();\n      isLongPress = false;\n      longPressTimer = setTimeout(function() {\n        isLongPress = true;\n      }, 300);\n    });\n    \n    magicArbuzCard.addEventListener('mouseup', function() {\n      clearTimeout(longPressTimer);\n      const clickDuration = Date.now() - clickStartTime;\n      if (clickDuration < 300 && !isLongPress) {\n        if (currentLang === 'eng') {\n          messageDiv.textContent = cardData.message_cn;\n          messageDiv.style.fontFamily = '\\'Noto Serif SC\\', serif';\n          messageDiv.style.fontSize = '18px';\n          messageDiv.style.letterSpacing = '0.5px';\n          messageDiv.style.minHeight = '60px';\n          if (!cardData.isSpecialCard) {\n            titleDiv.textContent = cardData.title_cn;\n            titleDiv.style.fontFamily = '\\'Noto Serif SC\\', serif';\n            titleDiv.style.fontSize = '18px';\n            titleDiv.style.letterSpacing = '0.5px';\n          }\n          titleDiv.style.minHeight = '30px';\n          currentLang = 'cn';\n        } else {\n          messageDiv.textContent = cardData.message_eng;\n          messageDiv.style.fontFamily = '\\'Cormorant Garamond\\', serif';\n          messageDiv.style.fontSize = '20px';\n          messageDiv.style.letterSpacing = '1px';\n          messageDiv.style.minHeight = '60px';\n          titleDiv.textContent = cardData.title;\n          titleDiv.style.fontFamily = 'serif';\n          titleDiv.style.fontSize = '20px';\n          titleDiv.style.letterSpacing = '1px';\n          titleDiv.style.minHeight = '30px';\n          currentLang = 'eng';\n        }\n      }\n    });\n  }\n}\n\nif (typeof document !== 'undefined') {\n  document.addEventListener('DOMContentLoaded', function() {\n    createMagicArbuzCard('magic-arbuz-container');\n  });\n}");
+    // Text goes through escape::js_string inside cardData and escape::template_html inside the html template
+    let js = template::render(template::CARD_JS, &[
+      ("title_js", &escape::js_string(card_title_value)),
+      ("title_cn_js", &escape::js_string(card_title_cn_value)),
+      ("is_special_card", &is_special_card.to_string()),
+      ("card_number_js", &escape::js_string(&index_display)),
+      ("message_eng_js", &escape::js_string(&prediction_eng)),
+      ("message_cn_js", &escape::js_string(&prediction_cn)),
+      ("language_js", &escape::js_string(language.as_str())),
+      ("background", &background_value),
+      ("border_color", &border_color_value),
+      ("glow_color", &glow_color_value),
+      ("card_number", &escape::template_html(&index_display)),
+      ("card_title", &card_title_text),
+      ("main_symbol", &escape::template_html(main_symbol_value)),
+      ("mystical_symbols", &mystical_symbols),
+      ("message", &message_text),
+      ("font_import", font_import),
+      ("latin_font", latin_font),
+      ("cjk_font", cjk_font),
+      ("latin_font_js", &escape::js_string(latin_font)),
+      ("cjk_font_js", &escape::js_string(cjk_font)),
+    ])?;
 
     Ok(js)
   }
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+pub(crate) mod tests {
+  use super::*;
+  use crate::escape::tests::{markup_outline, ADVERSARIAL};
+
+  // (index, background, main symbol, mystical symbols, card title, border color, glow color)
+  type TraitKeys = (u128, &'static str, &'static str, [&'static str; 3], &'static str, &'static str, &'static str);
+
+  // Traits as the original string based decoder produced them, covering genesis, classic, glitch and absolute cards
+  const BASELINE_TRAITS: &[TraitKeys] = &[
+    (0, "ethereal_white", "genesis", ["genesis", "genesis", "genesis"], "genesis", "gold", "gold"),
+    (1, "toxic_lime", "acai", ["clock", "taco", "acai"], "acai_card", "gold", "gold"),
+    (2, "cosmic_blue", "hermit", ["empress", "hanged_man", "fool"], "the_hermit", "gold", "gold"),
+    (3, "arctic_aqua", "world", ["devil", "hermit", "death"], "the_world", "blue", "red"),
+    (4, "abyss_blue", "star", ["moon", "hanged_man", "sun"], "the_star", "green", "green"),
+    (5, "blood_red", "empress", ["world", "moon", "hermit"], "the_empress", "purple", "purple"),
+    (6, "obsidian_black", "hermit", ["lovers", "star", "fool"], "the_hermit", "blue", "green"),
+    (7, "blood_red", "priestess", ["emperor", "magician", "hanged_man"], "the_priestess", "blue", "gold"),
+    (8, "emerald_green", "sun", ["hermit", "death", "wheel"], "the_sun", "purple", "green"),
+    (9, "blood_red", "priestess", ["hermit", "justice", "moon"], "the_priestess", "purple", "red"),
+    (10, "lava_orange", "tower", ["world", "priestess", "magician"], "the_tower", "gold", "gold"),
+    (11, "cosmic_blue", "moon", ["chariot", "wheel", "judgement"], "the_moon", "bronze", "red"),
+    (28, "cyber_yellow", "clock", ["diesel", "taco", "flask"], "clockin_card", "gold", "gold"),
+    (46, "golden_mystic", "diesel", ["puppet", "taco", "chick"], "diesel_card", "gold", "gold"),
+    (49, "cyber_yellow", "flask", ["acai", "balloon", "clock"], "mist_card", "gold", "gold"),
+    (65, "mystical_purple", "taco", ["taco", "taco", "diesel"], "taco_card", "gold", "gold"),
+    (74, "lava_orange", "balloon", ["acai", "clock", "puppet"], "airhead_card", "gold", "gold"),
+    (175, "ultraviolet", "chick", ["puppet", "taco", "puppet"], "cheekyb_card", "gold", "gold"),
+    (281, "lava_orange", "fartane", ["fartane", "arbuz", "fartane"], "fartane_card", "silver", "silver"),
+    (1012, "blood_red", "arbuz", ["fartane", "fartane", "fartane"], "arbuz_card", "green", "green"),
+  ];
+
+  #[test]
+  fn decode_traits_matches_baseline() {
+    for (index, background, main_symbol, mystical_symbols, card_title, border_color, glow_color) in BASELINE_TRAITS {
+      let traits = JsGenerator::decode_traits(*index, &[]).unwrap();
+      assert_eq!(traits.background.as_str(), *background, "background of card {}", index);
+      assert_eq!(traits.main_symbol.as_str(), *main_symbol, "main symbol of card {}", index);
+      assert_eq!(traits.mystical_symbols.map(|symbol| symbol.as_str()), *mystical_symbols, "mystical symbols of card {}", index);
+      assert_eq!(traits.card_title.as_str(), *card_title, "card title of card {}", index);
+      assert_eq!(traits.border_color.as_str(), *border_color, "border color of card {}", index);
+      assert_eq!(traits.glow_color.as_str(), *glow_color, "glow color of card {}", index);
+    }
+  }
+
+  // Card whose every text and style value is `value`
+  pub(crate) fn face_of(value: &'static str) -> CardFace {
+    CardFace {
+      background: value,
+      main_symbol: value,
+      mystical_symbols: [value; 3],
+      card_title: value,
+      card_title_cn: value,
+      is_special_card: false,
+      border_color: value,
+      glow_color: value,
+    }
+  }
+
+  // Openings and closings of every string literal, template literal and ${} substitution in a script, with
+  // a ! for a line break inside a quoted string. A value that gets out of its literal changes the outline.
+  fn literal_outline(js: &str) -> String {
+    let chars: Vec<char> = js.chars().collect();
+    let mut outline = String::new();
+    // '`' template literal, '$' substitution, '{' block inside a substitution
+    let mut contexts: Vec<char> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+      let c = chars[i];
+      let next = chars.get(i + 1).copied();
+      if contexts.last() == Some(&'`') {
+        match c {
+          '\\' => i += 1,
+          '`' => {
+            contexts.pop();
+            outline.push(c);
+          }
+          '$' if next == Some('{') => {
+            contexts.push('$');
+            outline.push_str("${");
+            i += 1;
+          }
+          _ => {}
+        }
+      } else {
+        match c {
+          '\'' | '"' => {
+            outline.push(c);
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+              match chars[i] {
+                '\\' => i += 1,
+                '\n' | '\r' | '\u{2028}' | '\u{2029}' => outline.push('!'),
+                _ => {}
+              }
+              i += 1;
+            }
+            outline.push(c);
+          }
+          '`' => {
+            contexts.push(c);
+            outline.push(c);
+          }
+          '/' if next == Some('/') => {
+            while i < chars.len() && chars[i] != '\n' {
+              i += 1;
+            }
+          }
+          '/' if next == Some('*') => {
+            i += 2;
+            while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+              i += 1;
+            }
+            i += 1;
+          }
+          '{' if !contexts.is_empty() => contexts.push(c),
+          '}' => match contexts.last() {
+            Some('$') => {
+              contexts.pop();
+              outline.push(c);
+            }
+            Some('{') => {
+              contexts.pop();
+            }
+            _ => {}
+          },
+          _ => {}
+        }
+      }
+      i += 1;
+    }
+    outline
+  }
+
+  fn predictions_of(value: &str) -> (String, String) {
+    (value.to_string(), value.to_string())
+  }
+
+  const LANGUAGES: [Language; 3] = [Language::English, Language::Chinese, Language::Both];
+  const FONT_MODES: [FontMode; 2] = [FontMode::WebFonts, FontMode::SystemFonts];
+
+  #[test]
+  fn adversarial_text_stays_inside_js_literals() {
+    for fonts in FONT_MODES {
+      for language in LANGUAGES {
+        let plain = JsGenerator::render_js(7, &face_of("plain"), predictions_of("plain"), fonts, language).unwrap();
+        for value in ADVERSARIAL {
+          let js = JsGenerator::render_js(7, &face_of(value), predictions_of(value), fonts, language).unwrap();
+          assert_eq!(literal_outline(&js), literal_outline(&plain), "{:?} broke out of a literal", value);
+          assert!(!js.to_lowercase().contains("</script"), "{:?} closed the script", value);
+          assert!(!js.contains("<!--"), "{:?} opened an HTML comment", value);
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn adversarial_text_stays_inside_the_html_script_block() {
+    for language in LANGUAGES {
+      let plain = JsGenerator::render_html(7, &face_of("plain"), predictions_of("plain"), FontMode::WebFonts, language).unwrap();
+      for value in ADVERSARIAL {
+        let html = JsGenerator::render_html(7, &face_of(value), predictions_of(value), FontMode::WebFonts, language).unwrap();
+        assert_eq!(html.to_lowercase().matches("</script").count(), 1, "{:?} closed the script early", value);
+
+        // Outside the script the page keeps its markup, inside it the script keeps its literals
+        let (head, rest) = html.split_once("<script>").unwrap();
+        let (script, tail) = rest.split_once("</script>").unwrap();
+        let (plain_head, plain_rest) = plain.split_once("<script>").unwrap();
+        let (plain_script, plain_tail) = plain_rest.split_once("</script>").unwrap();
+        assert_eq!(markup_outline(head), markup_outline(plain_head), "{:?} broke the page head", value);
+        assert_eq!(markup_outline(tail), markup_outline(plain_tail), "{:?} broke the page body", value);
+        assert_eq!(literal_outline(script), literal_outline(plain_script), "{:?} broke out of a literal", value);
+      }
+    }
+  }
+}
@@ -2,8 +2,8 @@ use metashrew_support::index_pointer::KeyValuePointer;
 use metashrew_support::compat::to_arraybuffer_layout;
 
 use alkanes_runtime::{
-  declare_alkane, message::MessageDispatch, storage::StoragePointer, token::Token,
-  runtime::AlkaneResponder
+  auth::AuthenticatedResponder, declare_alkane, message::MessageDispatch,
+  storage::StoragePointer, token::Token, runtime::AlkaneResponder
 };
 
 use alkanes_support::{
@@ -12,34 +12,60 @@ use alkanes_support::{
 };
 
 use anyhow::{anyhow, Result};
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
 
-mod js_generator;
-use js_generator::JsGenerator;
+// Upper bound on records returned by a single GetInstances call
+const MAX_INSTANCES_PAGE: u128 = 500;
 
-mod predict_generator;
-mod roman_numerals;
+// Sale phases, presale requires the pass alkane and gets the discounted price
+const PHASE_PUBLIC: u128 = 0;
+const PHASE_PRESALE: u128 = 1;
 
-const CHILD_ORBITAL_TEMPLATE_ID: u128 = n; // fill me
+pub mod card_traits;
 
-const ARBUZ_TOKEN_TEMPLATE: AlkaneId = AlkaneId {
-  block: n, // fill me
-  tx: n, // fill me
-};
+pub mod js_generator;
+pub mod svg_generator;
+use js_generator::{FontMode, JsGenerator, Language, UNREVEALED_TIER};
+use svg_generator::SvgGenerator;
+use pricing::{BASIS_POINTS, PRICING_EXPONENTIAL, PRICING_FIXED};
+
+mod clockin;
+mod escape;
+mod predict_generator;
+mod pricing;
+mod roman_numerals;
+mod template;
 
 #[derive(Default)]
 pub struct MagicArbuzCollection(());
 
 impl AlkaneResponder for MagicArbuzCollection {}
 
+impl AuthenticatedResponder for MagicArbuzCollection {}
+
 #[derive(MessageDispatch)]
 enum MagicArbuzCollectionMessage {
   #[opcode(0)]
-  Initialize,
+  Initialize {
+    orbital_template_id: u128,
+    payment_token_block: u128,
+    payment_token_tx: u128,
+    clockin_block: u128,
+    clockin_tx: u128,
+    mint_price: u128,
+    max_supply: u128,
+  },
 
   #[opcode(77)]
   MintOrbital,
 
+  #[opcode(78)]
+  MintOrbitals { count: u128 },
+
+  #[opcode(79)]
+  Reveal,
+
   #[opcode(99)]
   #[returns(String)]
   GetName,
@@ -56,6 +82,120 @@ enum MagicArbuzCollectionMessage {
   #[returns(u128)]
   GetOrbitalCount,
 
+  #[opcode(103)]
+  #[returns(u128)]
+  GetOrbitalTemplateId,
+
+  #[opcode(105)]
+  #[returns(Vec<u8>)]
+  GetClockinContract,
+
+  #[opcode(106)]
+  #[returns(u128)]
+  GetMintPrice { block: u128, tx: u128 },
+
+  #[opcode(107)]
+  #[returns(u128)]
+  GetMaxSupply,
+
+  #[opcode(108)]
+  #[returns(u128)]
+  GetTreasuryBalance { block: u128, tx: u128 },
+
+  #[opcode(109)]
+  #[returns(u128)]
+  GetFeesCollected { block: u128, tx: u128 },
+
+  #[opcode(110)]
+  #[returns(Vec<u8>)]
+  GetPricingPolicy,
+
+  #[opcode(111)]
+  #[returns(bool)]
+  IsPaused,
+
+  #[opcode(112)]
+  #[returns(u128)]
+  GetPhase,
+
+  #[opcode(113)]
+  #[returns(Vec<u8>)]
+  GetPresaleConfig,
+
+  #[opcode(114)]
+  #[returns(Vec<u8>)]
+  GetAcceptedPayments,
+
+  #[opcode(115)]
+  #[returns(Vec<u8>)]
+  GetFeeSplit,
+
+  #[opcode(116)]
+  #[returns(u128)]
+  GetLocked { block: u128, tx: u128 },
+
+  #[opcode(117)]
+  #[returns(u128)]
+  GetCreatorPaidOut { block: u128, tx: u128 },
+
+  #[opcode(118)]
+  #[returns(u128)]
+  GetCreatorOwed { block: u128, tx: u128 },
+
+  #[opcode(119)]
+  #[returns(bool)]
+  IsClockinEnabled,
+
+  #[opcode(120)]
+  #[returns(u128)]
+  GetRevealHeight,
+
+  #[opcode(121)]
+  #[returns(u128)]
+  GetFontMode,
+
+  #[opcode(200)]
+  WithdrawTreasury { block: u128, tx: u128, amount: u128 },
+
+  #[opcode(201)]
+  SetPricing { mode: u128, step_bps: u128, interval: u128 },
+
+  #[opcode(202)]
+  Pause,
+
+  #[opcode(203)]
+  Unpause,
+
+  #[opcode(204)]
+  SetPresale { pass_block: u128, pass_tx: u128, discount_bps: u128, public_height: u128 },
+
+  #[opcode(205)]
+  SetPhase { phase: u128 },
+
+  #[opcode(206)]
+  SetPaymentPrice { block: u128, tx: u128, price: u128 },
+
+  #[opcode(207)]
+  RemovePaymentToken { block: u128, tx: u128 },
+
+  #[opcode(208)]
+  SetFeeSplit { lock_bps: u128, creator_bps: u128, creator_block: u128, creator_tx: u128 },
+
+  #[opcode(209)]
+  ClaimCreatorPayout { block: u128, tx: u128 },
+
+  #[opcode(210)]
+  SetClockin { block: u128, tx: u128, enabled: u128, accept_empty: u128 },
+
+  #[opcode(211)]
+  SetRevealHeight { height: u128 },
+
+  #[opcode(212)]
+  SetFontMode { mode: u128 },
+
+  #[opcode(213)]
+  RedirectCreatorPayout { creator_block: u128, creator_tx: u128 },
+
   #[opcode(999)]
   #[returns(String)]
   GetAttributes { index: u128 },
@@ -71,6 +211,43 @@ enum MagicArbuzCollectionMessage {
   #[opcode(1002)]
   #[returns(String)]
   GetInstanceIdentifier { index: u128 },
+
+  #[opcode(1003)]
+  #[returns(u128)]
+  GetIndexByAlkaneId { block: u128, tx: u128 },
+
+  #[opcode(1004)]
+  #[returns(Vec<u8>)]
+  GetInstances { offset: u128, limit: u128 },
+
+  #[opcode(1005)]
+  #[returns(String)]
+  GetTier { index: u128 },
+
+  // English only, `image` and `animation_url` are the English SVG and HTML views
+  #[opcode(1006)]
+  #[returns(String)]
+  GetMetadata { index: u128 },
+
+  #[opcode(1007)]
+  #[returns(String)]
+  GetImage { index: u128 },
+
+  #[opcode(1008)]
+  #[returns(String)]
+  GetHtml { index: u128 },
+
+  #[opcode(1009)]
+  #[returns(String)]
+  GetLocalizedData { index: u128, language: u128 },
+
+  #[opcode(1010)]
+  #[returns(String)]
+  GetLocalizedImage { index: u128, language: u128 },
+
+  #[opcode(1011)]
+  #[returns(String)]
+  GetLocalizedHtml { index: u128, language: u128 },
 }
 
 impl Token for MagicArbuzCollection {
@@ -84,23 +261,53 @@ impl Token for MagicArbuzCollection {
 }
 
 impl MagicArbuzCollection {
-  fn initialize(&self) -> Result<CallResponse> {
+  // One parameter per Initialize opcode field
+  #[allow(clippy::too_many_arguments)]
+  fn initialize(
+    &self,
+    orbital_template_id: u128,
+    payment_token_block: u128,
+    payment_token_tx: u128,
+    clockin_block: u128,
+    clockin_tx: u128,
+    mint_price: u128,
+    max_supply: u128,
+  ) -> Result<CallResponse> {
     self.observe_initialization()?;
     let context = self.context()?;
 
-    let response = CallResponse::forward(&context.incoming_alkanes);
+    if max_supply == 0 {
+      return Err(anyhow!("Max supply must be greater than zero"));
+    }
+
+    self.set_orbital_template_id(orbital_template_id);
+    self.store_payment_price(&AlkaneId { block: payment_token_block, tx: payment_token_tx }, mint_price)?;
+    // Clock-in contract 0:0 deploys without the gate, e.g. on local regtest
+    let clockin_id = AlkaneId { block: clockin_block, tx: clockin_tx };
+    self.set_clockin_contract(&clockin_id);
+    self.set_clockin_enabled(clockin_id != AlkaneId::default());
+    self.store_pricing(PRICING_FIXED, 0, 0);
+    self.set_max_supply(max_supply);
+
+    // Single auth token, whoever holds it administers the collection
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+    response.alkanes.0.push(self.deploy_auth_token(1u128)?);
 
     Ok(response)
   }
 
+  // Admin opcodes must be called with the auth token, which is returned back with the rest of incoming alkanes
+  fn only_owner_response(&self) -> Result<CallResponse> {
+    self.only_owner()?;
+    let context = self.context()?;
+
+    Ok(CallResponse::forward(&context.incoming_alkanes))
+  }
+
 
   fn external_clockin_check(&self) -> Result<CallResponse> {
-      let clockin_id = AlkaneId {
-          block: n, // fill me
-          tx: n, // fill me
-      };
       let cellpack = Cellpack {
-          target: clockin_id,
+          target: self.clockin_contract()?,
           inputs: vec![103],
       };
       let response = self.call(
@@ -112,58 +319,361 @@ impl MagicArbuzCollection {
   }
 
   fn mint_orbital(&self) -> Result<CallResponse> {
+    self.mint_orbitals(1)
+  }
+
+  fn mint_orbitals(&self, count: u128) -> Result<CallResponse> {
     let context = self.context()?;
 
-    // Find ARBUZ token in incoming alkanes
-    let arbuz_transfer = context.incoming_alkanes.0.iter()
-      .find(|transfer| transfer.id == ARBUZ_TOKEN_TEMPLATE);
+    // Erroring reverts the call, so every incoming alkane goes back to the minter
+    if self.is_paused_flag() {
+      return Err(anyhow!("Minting is paused, incoming alkanes are refunded"));
+    }
+
+    if count == 0 {
+      return Err(anyhow!("Mint count must be greater than zero"));
+    }
+
+    let first_index = self.instances_count();
+    let end_index = first_index.checked_add(count)
+      .ok_or_else(|| anyhow!("instances count overflow"))?;
+    if first_index >= self.max_supply() {
+      return Err(anyhow!("Collection is sold out, all {} orbitals are minted", self.max_supply()));
+    }
+    if end_index > self.max_supply() {
+      return Err(anyhow!("Only {} orbitals are left to mint", self.max_supply() - first_index));
+    }
+
+    // Presale mints must present the pass, it goes back with the other non-payment alkanes below
+    if self.current_phase() == PHASE_PRESALE {
+      let pass = self.presale_pass()?;
+      let has_pass = context.incoming_alkanes.0.iter()
+        .any(|transfer| transfer.id == pass && transfer.value > 0);
+      if !has_pass {
+        return Err(anyhow!("Presale mint requires the pass alkane {}:{}", pass.block, pass.tx));
+      }
+    }
+
+    // Pay with the first accepted token found in incoming alkanes, in payment table order
+    let accepted_payments = self.accepted_payments()?;
+    let payment = accepted_payments.iter().find_map(|(token, base_price)| {
+      context.incoming_alkanes.0.iter()
+        .find(|transfer| transfer.id == *token)
+        .map(|transfer| (transfer, *base_price))
+    });
 
-    if arbuz_transfer.is_none() {
-      return Err(anyhow!("Incoming alkanes must include ARBUZ"));
+    if payment.is_none() {
+      return Err(anyhow!("Incoming alkanes must include an accepted payment token"));
     }
 
-    let arbuz_transfer = arbuz_transfer.unwrap();
+    let (payment_transfer, base_price) = payment.unwrap();
+    let payment_token = payment_transfer.id;
 
-    // Check if at least 100 ARBUZ tokens are provided (considering divisibility of 8)
-    let required_arbuz_amount = 100 * 100_000_000u128; // 100 ARBUZ with divisibility 8
-    if arbuz_transfer.value < required_arbuz_amount {
-      return Err(anyhow!("Mint cost is at least 100 ARBUZ tokens"));
+    // Check if enough payment tokens are provided, each orbital is priced at its own point on the curve
+    let mut required_amount = 0u128;
+    for index in first_index..end_index {
+      required_amount = required_amount.checked_add(self.quote_price_at(base_price, index)?)
+        .ok_or_else(|| anyhow!("mint price overflow"))?;
+    }
+    if payment_transfer.value < required_amount {
+      return Err(anyhow!(
+        "Mint cost is at least {} base units of {}:{}",
+        required_amount, payment_token.block, payment_token.tx
+      ));
     }
 
-    let clockin_result = self.external_clockin_check();
-    if clockin_result.is_err() {
-        return Err(anyhow!("Invalid clock-in block, cards say better luck next time"));
+    if self.clockin_enabled() {
+      let approved = match self.external_clockin_check() {
+        Ok(clockin_response) => {
+          clockin::verdict(&clockin_response.data, self.height() as u128, self.clockin_accepts_empty())?
+        }
+        Err(_) => return Err(anyhow!("Invalid clock-in block, cards say better luck next time")),
+      };
+      if !approved {
+        return Err(anyhow!("Clock-in says no, cards say better luck next time"));
+      }
     }
 
-    // Return arbuz card and hold 100 ARBUZ tokens in contract
+    // Return arbuz cards and hold the mint price in contract, any failed deploy reverts the whole call
+    // Loading the block and transaction is the costly part of card entropy, so it happens once per call
+    let block_hash = self.block_hash();
+    let transaction_digest = Sha256::digest(self.transaction()).to_vec();
     let mut response = CallResponse::default();
-    response.alkanes.0.push(self.create_mint_transfer()?);
+    for _ in 0..count {
+      response.alkanes.0.push(self.create_mint_transfer(&block_hash, &transaction_digest)?);
+    }
+    self.split_fee(&payment_token, required_amount)?;
     
-    // Return excess ARBUZ tokens back to user
-    if arbuz_transfer.value > required_arbuz_amount {
+    // Return excess payment tokens back to user
+    if payment_transfer.value > required_amount {
       response.alkanes.0.push(AlkaneTransfer {
-        id: ARBUZ_TOKEN_TEMPLATE,
-        value: arbuz_transfer.value - required_arbuz_amount,
+        id: payment_token,
+        value: payment_transfer.value - required_amount,
       });
     }
     
-    // Return other alkanes back if not ARBUZ token
+    // Return other alkanes back if not the payment token
     for transfer in &context.incoming_alkanes.0 {
-      if transfer.id != ARBUZ_TOKEN_TEMPLATE {
+      if transfer.id != payment_token {
         response.alkanes.0.push(transfer.clone());
       }
     }
 
     Ok(response)
-}
+  }
+
+  fn withdraw_treasury(&self, block: u128, tx: u128, amount: u128) -> Result<CallResponse> {
+    let mut response = self.only_owner_response()?;
+    let token = AlkaneId { block, tx };
+    let treasury_balance = self.treasury_balance(&token)?;
+
+    if amount == 0 {
+      return Err(anyhow!("Withdraw amount must be greater than zero"));
+    }
+    if amount > treasury_balance {
+      return Err(anyhow!("Treasury holds only {} base units of {}:{}", treasury_balance, block, tx));
+    }
+
+    response.alkanes.0.push(AlkaneTransfer {
+      id: token,
+      value: amount,
+    });
+
+    Ok(response)
+  }
+
+  fn set_pricing(&self, mode: u128, step_bps: u128, interval: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+
+    if mode > PRICING_EXPONENTIAL {
+      return Err(anyhow!("Unknown pricing mode {}", mode));
+    }
+    if mode != PRICING_FIXED && interval == 0 {
+      return Err(anyhow!("Pricing interval must be greater than zero"));
+    }
+
+    self.store_pricing(mode, step_bps, interval);
+
+    Ok(response)
+  }
+
+  fn pause(&self) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    self.set_paused(true);
+
+    Ok(response)
+  }
+
+  fn unpause(&self) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    self.set_paused(false);
+
+    Ok(response)
+  }
 
-  fn create_mint_transfer(&self) -> Result<AlkaneTransfer> {
+  fn set_presale(&self, pass_block: u128, pass_tx: u128, discount_bps: u128, public_height: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let pass = AlkaneId { block: pass_block, tx: pass_tx };
+
+    if self.payment_base_price(&pass)?.is_some() {
+      return Err(anyhow!("Presale pass cannot be an accepted payment token"));
+    }
+    if discount_bps > BASIS_POINTS {
+      return Err(anyhow!("Presale discount cannot exceed {} basis points", BASIS_POINTS));
+    }
+
+    self.presale_pointer().keyword("/pass").set(Arc::new(Self::alkane_id_to_bytes(&pass)));
+    self.presale_pointer().keyword("/discount-bps").set_value(discount_bps);
+    self.presale_pointer().keyword("/public-height").set_value(public_height);
+
+    Ok(response)
+  }
+
+  fn set_phase(&self, phase: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+
+    if phase > PHASE_PRESALE {
+      return Err(anyhow!("Unknown sale phase {}", phase));
+    }
+    if phase == PHASE_PRESALE {
+      self.presale_pass()?;
+    }
+
+    self.phase_pointer().set_value(phase);
+
+    Ok(response)
+  }
+
+  fn set_payment_price(&self, block: u128, tx: u128, price: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let token = AlkaneId { block, tx };
+
+    if self.presale_pass().ok() == Some(token) {
+      return Err(anyhow!("Presale pass cannot be an accepted payment token"));
+    }
+
+    self.store_payment_price(&token, price)?;
+
+    Ok(response)
+  }
+
+  fn remove_payment_token(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let token = AlkaneId { block, tx };
+
+    let mut payments = self.accepted_payments()?;
+    let position = payments.iter().position(|(id, _)| *id == token)
+      .ok_or_else(|| anyhow!("{}:{} is not an accepted payment token", block, tx))?;
+    payments.remove(position);
+    self.store_payments(&payments);
+
+    Ok(response)
+  }
+
+  fn set_fee_split(&self, lock_bps: u128, creator_bps: u128, creator_block: u128, creator_tx: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+
+    let total_bps = lock_bps.checked_add(creator_bps)
+      .ok_or_else(|| anyhow!("fee split overflow"))?;
+    if total_bps > BASIS_POINTS {
+      return Err(anyhow!("Locked and creator shares cannot exceed {} basis points", BASIS_POINTS));
+    }
+
+    // Nobody can present 0:0 at ClaimCreatorPayout, the creator share would be stuck for good
+    let creator = AlkaneId { block: creator_block, tx: creator_tx };
+    if creator_bps > 0 && creator == AlkaneId::default() {
+      return Err(anyhow!("Creator share requires a creator payout alkane"));
+    }
+
+    // Fees already accrued belong to the current payee, moving them takes RedirectCreatorPayout
+    if self.fee_creator().ok() != Some(creator) && self.creator_owed_tokens() > 0 {
+      return Err(anyhow!("Creator payout alkane cannot change while the creator is still owed fees, use RedirectCreatorPayout"));
+    }
+
+    self.fee_split_pointer().keyword("/lock-bps").set_value(lock_bps);
+    self.fee_split_pointer().keyword("/creator-bps").set_value(creator_bps);
+    self.fee_split_pointer().keyword("/creator").set(Arc::new(Self::alkane_id_to_bytes(&creator)));
+
+    Ok(response)
+  }
+
+  // Hands the payout, including everything still owed, to another alkane, e.g. when the creator
+  // loses theirs or it was mistyped in SetFeeSplit
+  fn redirect_creator_payout(&self, creator_block: u128, creator_tx: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let creator = AlkaneId { block: creator_block, tx: creator_tx };
+
+    if creator == AlkaneId::default() {
+      return Err(anyhow!("Creator payout alkane cannot be 0:0"));
+    }
+
+    self.fee_split_pointer().keyword("/creator").set(Arc::new(Self::alkane_id_to_bytes(&creator)));
+
+    Ok(response)
+  }
+
+  // Whoever presents the creator payout alkane receives everything owed in the given token, so it
+  // must be a unique token such as an auth token: any holder of a fungible one could claim it all
+  fn claim_creator_payout(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+    let token = AlkaneId { block, tx };
+
+    let creator = self.fee_creator()?;
+    let has_creator = context.incoming_alkanes.0.iter()
+      .any(|transfer| transfer.id == creator && transfer.value > 0);
+    if !has_creator {
+      return Err(anyhow!("Claim requires the creator payout alkane {}:{}", creator.block, creator.tx));
+    }
+
+    let owed = self.creator_owed(&token);
+    if owed == 0 {
+      return Err(anyhow!("Nothing is owed to the creator in {}:{}", block, tx));
+    }
+
+    self.add_token_counter("/creator-paid", &token, owed)?;
+    self.set_creator_owed_tokens(self.creator_owed_tokens().saturating_sub(1));
+    response.alkanes.0.push(AlkaneTransfer {
+      id: token,
+      value: owed,
+    });
+
+    Ok(response)
+  }
+
+  // `accept_empty` takes an empty answer as approval, only for older clock-in contracts that don't
+  // return a verdict, with any other contract it would approve every mint
+  fn set_clockin(&self, block: u128, tx: u128, enabled: u128, accept_empty: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let clockin_id = AlkaneId { block, tx };
+
+    if enabled != 0 && clockin_id == AlkaneId::default() {
+      return Err(anyhow!("Clock-in gate cannot be enabled without a clock-in contract"));
+    }
+
+    self.set_clockin_contract(&clockin_id);
+    self.set_clockin_enabled(enabled != 0);
+    self.clockin_accept_empty_pointer().set_value((accept_empty != 0) as u8);
+
+    Ok(response)
+  }
+
+  fn set_reveal_height(&self, height: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+
+    // Traits of revealed cards are final, a new reveal would reshuffle them
+    if self.reveal_seed().is_some() {
+      return Err(anyhow!("Collection is already revealed"));
+    }
+    // Minted cards are either public already or waiting on a reveal, dropping it would serve them
+    // from the public mint entropy and let the owner pick whichever outcome they prefer
+    if self.instances_count() > 0 {
+      return Err(anyhow!("Reveal height is fixed once minting has started"));
+    }
+
+    self.reveal_height_pointer().set_value(height);
+
+    Ok(response)
+  }
+
+  fn set_font_mode(&self, mode: u128) -> Result<CallResponse> {
+    let response = self.only_owner_response()?;
+    let fonts = FontMode::from_code(mode)?;
+    self.font_mode_pointer().set_value(fonts.code());
+
+    Ok(response)
+  }
+
+  // Anyone can trigger the reveal once the reveal height is reached. The hash of the block it lands in
+  // is mixed into every card's entropy, nobody knows it while cards are minted, so traits stay hidden
+  // until then even though the mint entropy is public.
+  fn reveal(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let response = CallResponse::forward(&context.incoming_alkanes);
+
+    let reveal_height = self.reveal_height();
+    if reveal_height == 0 {
+      return Err(anyhow!("Collection has no delayed reveal"));
+    }
+    if self.reveal_seed().is_some() {
+      return Err(anyhow!("Collection is already revealed"));
+    }
+    if (self.height() as u128) < reveal_height {
+      return Err(anyhow!("Cards are revealed at block {}", reveal_height));
+    }
+
+    self.reveal_seed_pointer().set(Arc::new(self.block_hash()));
+
+    Ok(response)
+  }
+
+  fn create_mint_transfer(&self, block_hash: &[u8], transaction_digest: &[u8]) -> Result<AlkaneTransfer> {
     let index = self.instances_count();
 
     let cellpack = Cellpack {
       target: AlkaneId {
         block: 6,
-        tx: CHILD_ORBITAL_TEMPLATE_ID,
+        tx: self.orbital_template_id(),
       },
       inputs: vec![0x0, index],
     };
@@ -171,6 +681,8 @@ impl MagicArbuzCollection {
     let sequence = self.sequence();
     let response = self.call(&cellpack, &AlkaneTransferParcel::default(), self.fuel())?;
 
+    self.set_instance_entropy(index, &Self::mint_entropy(block_hash, transaction_digest, index));
+
     let orbital_id = AlkaneId {
       block: 2,
       tx: sequence,
@@ -207,7 +719,7 @@ impl MagicArbuzCollection {
     let context = self.context()?;
     let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-    response.data = (&self.instances_count().to_le_bytes()).to_vec();
+    response.data = self.max_supply().to_le_bytes().to_vec();
 
     Ok(response)
   }
@@ -221,58 +733,658 @@ impl MagicArbuzCollection {
     Ok(response)
   }
 
-  fn get_data(&self, index: u128) -> Result<CallResponse> {
+  fn get_orbital_template_id(&self) -> Result<CallResponse> {
     let context = self.context()?;
     let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-    let js_code = JsGenerator::generate_js(index)?;
-    response.data = js_code.into_bytes();
+    response.data = self.orbital_template_id().to_le_bytes().to_vec();
+
     Ok(response)
   }
 
-  fn get_attributes(&self, index: u128) -> Result<CallResponse> {
+  fn get_clockin_contract(&self) -> Result<CallResponse> {
     let context = self.context()?;
     let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-    let attributes = JsGenerator::get_attributes(index)?;
-    response.data = attributes.into_bytes();
+    response.data = Self::alkane_id_to_bytes(&self.clockin_contract()?);
+
     Ok(response)
   }
 
-  fn instances_pointer(&self) -> StoragePointer {
-    StoragePointer::from_keyword("/instances")
-  }
+  fn get_mint_price(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-  fn instances_count(&self) -> u128 {
-    self.instances_pointer().get_value::<u128>()
-  }
+    let base_price = self.payment_base_price(&AlkaneId { block, tx })?
+      .ok_or_else(|| anyhow!("{}:{} is not an accepted payment token", block, tx))?;
+    response.data = self.quote_price_at(base_price, self.instances_count())?.to_le_bytes().to_vec();
 
-  fn set_instances_count(&self, count: u128) {
-    self.instances_pointer().set_value(count);
+    Ok(response)
   }
 
-  fn add_instance(&self, instance_id: &AlkaneId) -> Result<u128> {
-    let count = self.instances_count();
-    let new_count = count.checked_add(1)
-      .ok_or_else(|| anyhow!("instances count overflow"))?;
+  fn get_pricing_policy(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-    let mut bytes = Vec::with_capacity(32);
-    bytes.extend_from_slice(&instance_id.block.to_le_bytes());
-    bytes.extend_from_slice(&instance_id.tx.to_le_bytes());
+    let mut bytes = Vec::with_capacity(48);
+    bytes.extend_from_slice(&self.pricing_mode().to_le_bytes());
+    bytes.extend_from_slice(&self.price_step_bps().to_le_bytes());
+    bytes.extend_from_slice(&self.price_interval().to_le_bytes());
 
-    let bytes_vec = new_count.to_le_bytes().to_vec();
-    let mut instance_pointer = self.instances_pointer().select(&bytes_vec);
-    instance_pointer.set(Arc::new(bytes));
-    
-    self.set_instances_count(new_count);
-    
-    Ok(new_count)
+    response.data = bytes;
+
+    Ok(response)
   }
 
+  fn get_max_supply(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
 
-  fn lookup_instance(&self, index: u128) -> Result<AlkaneId> {
-    // Add 1 to index since instances are stored at 1-based indices
-    let storage_index = index + 1;
+    response.data = self.max_supply().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_treasury_balance(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.treasury_balance(&AlkaneId { block, tx })?.to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_fees_collected(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.token_counter("/fees-collected", &AlkaneId { block, tx }).to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn is_paused(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = vec![self.is_paused_flag() as u8];
+
+    Ok(response)
+  }
+
+  fn get_phase(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.current_phase().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_presale_config(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let mut bytes = Self::alkane_id_to_bytes(&self.presale_pass().unwrap_or_default());
+    bytes.extend_from_slice(&self.presale_discount_bps().to_le_bytes());
+    bytes.extend_from_slice(&self.presale_public_height().to_le_bytes());
+
+    response.data = bytes;
+
+    Ok(response)
+  }
+
+  // Count header followed by (block, tx, current price) records, prices include the presale discount
+  fn get_accepted_payments(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let payments = self.accepted_payments()?;
+    let next_index = self.instances_count();
+
+    let mut bytes = Vec::with_capacity(16 + payments.len() * 48);
+    bytes.extend_from_slice(&(payments.len() as u128).to_le_bytes());
+    for (token, base_price) in payments {
+      bytes.extend_from_slice(&Self::alkane_id_to_bytes(&token));
+      bytes.extend_from_slice(&self.quote_price_at(base_price, next_index)?.to_le_bytes());
+    }
+
+    response.data = bytes;
+
+    Ok(response)
+  }
+
+  fn get_fee_split(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let mut bytes = Vec::with_capacity(64);
+    bytes.extend_from_slice(&self.fee_lock_bps().to_le_bytes());
+    bytes.extend_from_slice(&self.fee_creator_bps().to_le_bytes());
+    bytes.extend_from_slice(&Self::alkane_id_to_bytes(&self.fee_creator().unwrap_or_default()));
+
+    response.data = bytes;
+
+    Ok(response)
+  }
+
+  fn get_locked(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.token_counter("/locked", &AlkaneId { block, tx }).to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_creator_paid_out(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.token_counter("/creator-paid", &AlkaneId { block, tx }).to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_creator_owed(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.creator_owed(&AlkaneId { block, tx }).to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn is_clockin_enabled(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = vec![self.clockin_enabled() as u8];
+
+    Ok(response)
+  }
+
+  fn get_reveal_height(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.reveal_height().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_font_mode(&self) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    response.data = self.font_mode().code().to_le_bytes().to_vec();
+
+    Ok(response)
+  }
+
+  fn get_data(&self, index: u128) -> Result<CallResponse> {
+    self.render_data(index, Language::English)
+  }
+
+  fn get_localized_data(&self, index: u128, language: u128) -> Result<CallResponse> {
+    self.render_data(index, Language::from_code(language)?)
+  }
+
+  fn render_data(&self, index: u128, language: Language) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let js_code = if self.is_revealed() {
+      JsGenerator::generate_js(index, &self.card_entropy(index), self.font_mode(), language)?
+    } else {
+      JsGenerator::generate_unrevealed_js(index, self.font_mode(), language)?
+    };
+    response.data = js_code.into_bytes();
+    Ok(response)
+  }
+
+  fn get_attributes(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let attributes = if self.is_revealed() {
+      JsGenerator::get_attributes(index, &self.card_entropy(index))?
+    } else {
+      JsGenerator::get_unrevealed_attributes(index)?
+    };
+    response.data = attributes.into_bytes();
+    Ok(response)
+  }
+
+  fn get_tier(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let tier = if self.is_revealed() {
+      JsGenerator::decode_traits(index, &self.card_entropy(index))?.tier.as_str()
+    } else {
+      UNREVEALED_TIER
+    };
+    response.data = tier.as_bytes().to_vec();
+    Ok(response)
+  }
+
+  fn get_metadata(&self, index: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let metadata = if self.is_revealed() {
+      JsGenerator::get_metadata(index, &self.card_entropy(index), self.font_mode())?
+    } else {
+      JsGenerator::get_unrevealed_metadata(index, self.font_mode())?
+    };
+    response.data = metadata.into_bytes();
+    Ok(response)
+  }
+
+  fn get_image(&self, index: u128) -> Result<CallResponse> {
+    self.render_image(index, Language::English)
+  }
+
+  fn get_localized_image(&self, index: u128, language: u128) -> Result<CallResponse> {
+    self.render_image(index, Language::from_code(language)?)
+  }
+
+  fn render_image(&self, index: u128, language: Language) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let svg = if self.is_revealed() {
+      SvgGenerator::generate_svg(index, &self.card_entropy(index), language)?
+    } else {
+      SvgGenerator::generate_unrevealed_svg(index, language)?
+    };
+    response.data = svg.into_bytes();
+    Ok(response)
+  }
+
+  fn get_html(&self, index: u128) -> Result<CallResponse> {
+    self.render_html(index, Language::English)
+  }
+
+  fn get_localized_html(&self, index: u128, language: u128) -> Result<CallResponse> {
+    self.render_html(index, Language::from_code(language)?)
+  }
+
+  fn render_html(&self, index: u128, language: Language) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    self.ensure_minted(index)?;
+    let html = if self.is_revealed() {
+      JsGenerator::generate_html(index, &self.card_entropy(index), self.font_mode(), language)?
+    } else {
+      JsGenerator::generate_unrevealed_html(index, self.font_mode(), language)?
+    };
+    response.data = html.into_bytes();
+    Ok(response)
+  }
+
+  fn alkane_id_to_bytes(id: &AlkaneId) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(32);
+    bytes.extend_from_slice(&id.block.to_le_bytes());
+    bytes.extend_from_slice(&id.tx.to_le_bytes());
+    bytes
+  }
+
+  fn alkane_id_from_bytes(bytes: &[u8]) -> Result<AlkaneId> {
+    if bytes.len() != 32 {
+      return Err(anyhow!("Invalid alkane id data length"));
+    }
+
+    let block = u128::from_le_bytes(bytes[..16].try_into().unwrap());
+    let tx = u128::from_le_bytes(bytes[16..].try_into().unwrap());
+
+    Ok(AlkaneId { block, tx })
+  }
+
+  fn orbital_template_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/orbital-template")
+  }
+
+  fn orbital_template_id(&self) -> u128 {
+    self.orbital_template_pointer().get_value::<u128>()
+  }
+
+  fn set_orbital_template_id(&self, template_id: u128) {
+    self.orbital_template_pointer().set_value(template_id);
+  }
+
+  fn payments_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/payments")
+  }
+
+  // Accepted payment tokens with their base price, stored at 1-based indices like instances
+  fn accepted_payments(&self) -> Result<Vec<(AlkaneId, u128)>> {
+    let count = self.payments_pointer().get_value::<u128>();
+    let mut payments = Vec::new();
+
+    for i in 1..=count {
+      let bytes = self.payments_pointer().select(&i.to_le_bytes().to_vec()).get();
+      if bytes.len() != 48 {
+        return Err(anyhow!("Invalid payment data length"));
+      }
+
+      let token = Self::alkane_id_from_bytes(&bytes[..32])?;
+      let base_price = u128::from_le_bytes(bytes[32..].try_into().unwrap());
+      payments.push((token, base_price));
+    }
+
+    Ok(payments)
+  }
+
+  fn store_payments(&self, payments: &[(AlkaneId, u128)]) {
+    for (i, (token, base_price)) in payments.iter().enumerate() {
+      let mut bytes = Self::alkane_id_to_bytes(token);
+      bytes.extend_from_slice(&base_price.to_le_bytes());
+
+      let storage_index = i as u128 + 1;
+      self.payments_pointer().select(&storage_index.to_le_bytes().to_vec()).set(Arc::new(bytes));
+    }
+
+    self.payments_pointer().set_value(payments.len() as u128);
+  }
+
+  fn payment_base_price(&self, token: &AlkaneId) -> Result<Option<u128>> {
+    Ok(self.accepted_payments()?.into_iter()
+      .find(|(id, _)| id == token)
+      .map(|(_, base_price)| base_price))
+  }
+
+  fn store_payment_price(&self, token: &AlkaneId, base_price: u128) -> Result<()> {
+    let mut payments = self.accepted_payments()?;
+
+    match payments.iter_mut().find(|(id, _)| id == token) {
+      Some(payment) => payment.1 = base_price,
+      None => payments.push((*token, base_price)),
+    }
+    self.store_payments(&payments);
+
+    Ok(())
+  }
+
+  fn clockin_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/clockin")
+  }
+
+  fn clockin_contract(&self) -> Result<AlkaneId> {
+    Self::alkane_id_from_bytes(&self.clockin_pointer().get())
+  }
+
+  fn set_clockin_contract(&self, contract_id: &AlkaneId) {
+    self.clockin_pointer().set(Arc::new(Self::alkane_id_to_bytes(contract_id)));
+  }
+
+  fn clockin_disabled_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/clockin-disabled")
+  }
+
+  fn clockin_enabled(&self) -> bool {
+    self.clockin_disabled_pointer().get_value::<u8>() == 0
+  }
+
+  fn set_clockin_enabled(&self, enabled: bool) {
+    self.clockin_disabled_pointer().set_value((!enabled) as u8);
+  }
+
+  fn clockin_accept_empty_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/clockin-accept-empty")
+  }
+
+  fn clockin_accepts_empty(&self) -> bool {
+    self.clockin_accept_empty_pointer().get_value::<u8>() == 1
+  }
+
+  fn pricing_mode(&self) -> u128 {
+    StoragePointer::from_keyword("/pricing/mode").get_value::<u128>()
+  }
+
+  fn price_step_bps(&self) -> u128 {
+    StoragePointer::from_keyword("/pricing/step-bps").get_value::<u128>()
+  }
+
+  fn price_interval(&self) -> u128 {
+    StoragePointer::from_keyword("/pricing/interval").get_value::<u128>()
+  }
+
+  fn store_pricing(&self, mode: u128, step_bps: u128, interval: u128) {
+    StoragePointer::from_keyword("/pricing/mode").set_value(mode);
+    StoragePointer::from_keyword("/pricing/step-bps").set_value(step_bps);
+    StoragePointer::from_keyword("/pricing/interval").set_value(interval);
+  }
+
+  // Price actually charged for the orbital at `index`, including the presale discount
+  fn quote_price_at(&self, base_price: u128, index: u128) -> Result<u128> {
+    let discount_bps = if self.current_phase() == PHASE_PRESALE { self.presale_discount_bps() } else { 0 };
+
+    pricing::price_at(base_price, self.pricing_mode(), self.price_step_bps(), self.price_interval(), index, discount_bps)
+  }
+
+  fn max_supply_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/max-supply")
+  }
+
+  fn max_supply(&self) -> u128 {
+    self.max_supply_pointer().get_value::<u128>()
+  }
+
+  fn set_max_supply(&self, max_supply: u128) {
+    self.max_supply_pointer().set_value(max_supply);
+  }
+
+  // Tokens held by the contract, excluding whatever was sent along with the current call,
+  // the locked share and the creator share that has not been claimed yet
+  fn treasury_balance(&self, token: &AlkaneId) -> Result<u128> {
+    let context = self.context()?;
+
+    let incoming_amount: u128 = context.incoming_alkanes.0.iter()
+      .filter(|transfer| transfer.id == *token)
+      .map(|transfer| transfer.value)
+      .sum();
+
+    Ok(self.balance(&context.myself, token)
+      .saturating_sub(incoming_amount)
+      .saturating_sub(self.token_counter("/locked", token))
+      .saturating_sub(self.creator_owed(token)))
+  }
+
+  fn fee_split_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/fee-split")
+  }
+
+  fn fee_lock_bps(&self) -> u128 {
+    self.fee_split_pointer().keyword("/lock-bps").get_value::<u128>()
+  }
+
+  fn fee_creator_bps(&self) -> u128 {
+    self.fee_split_pointer().keyword("/creator-bps").get_value::<u128>()
+  }
+
+  fn fee_creator(&self) -> Result<AlkaneId> {
+    Self::alkane_id_from_bytes(&self.fee_split_pointer().keyword("/creator").get())
+      .map_err(|_| anyhow!("Creator payout alkane is not configured"))
+  }
+
+  fn creator_owed(&self, token: &AlkaneId) -> u128 {
+    self.token_counter("/creator-accrued", token)
+      .saturating_sub(self.token_counter("/creator-paid", token))
+  }
+
+  // Number of payment tokens the creator has an unclaimed balance in
+  fn creator_owed_tokens(&self) -> u128 {
+    self.fee_split_pointer().keyword("/owed-tokens").get_value::<u128>()
+  }
+
+  fn set_creator_owed_tokens(&self, count: u128) {
+    self.fee_split_pointer().keyword("/owed-tokens").set_value(count);
+  }
+
+  // Alkanes has no burn address a contract can send to, response alkanes always go back to the
+  // caller. The locked share takes the tokens out of circulation instead: it stays in the contract
+  // and no opcode can move it.
+  fn split_fee(&self, token: &AlkaneId, amount: u128) -> Result<()> {
+    let (locked, creator_share) = pricing::fee_shares(amount, self.fee_lock_bps(), self.fee_creator_bps())?;
+
+    if creator_share > 0 && self.creator_owed(token) == 0 {
+      self.set_creator_owed_tokens(self.creator_owed_tokens() + 1);
+    }
+
+    self.add_token_counter("/fees-collected", token, amount)?;
+    self.add_token_counter("/locked", token, locked)?;
+    self.add_token_counter("/creator-accrued", token, creator_share)?;
+
+    Ok(())
+  }
+
+  fn paused_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/paused")
+  }
+
+  fn is_paused_flag(&self) -> bool {
+    self.paused_pointer().get_value::<u8>() == 1
+  }
+
+  fn set_paused(&self, paused: bool) {
+    self.paused_pointer().set_value(paused as u8);
+  }
+
+  fn phase_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/phase")
+  }
+
+  // Presale turns public by itself once the configured height is reached
+  fn current_phase(&self) -> u128 {
+    let phase = self.phase_pointer().get_value::<u128>();
+    let public_height = self.presale_public_height();
+    if phase == PHASE_PRESALE && public_height != 0 && self.height() as u128 >= public_height {
+      return PHASE_PUBLIC;
+    }
+
+    phase
+  }
+
+  fn presale_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/presale")
+  }
+
+  fn presale_pass(&self) -> Result<AlkaneId> {
+    Self::alkane_id_from_bytes(&self.presale_pointer().keyword("/pass").get())
+      .map_err(|_| anyhow!("Presale pass is not configured"))
+  }
+
+  fn presale_discount_bps(&self) -> u128 {
+    self.presale_pointer().keyword("/discount-bps").get_value::<u128>()
+  }
+
+  fn presale_public_height(&self) -> u128 {
+    self.presale_pointer().keyword("/public-height").get_value::<u128>()
+  }
+
+  // Running per-token totals such as "/fees-collected" and "/locked"
+  fn token_counter(&self, keyword: &str, token: &AlkaneId) -> u128 {
+    StoragePointer::from_keyword(keyword).select(&Self::alkane_id_to_bytes(token)).get_value::<u128>()
+  }
+
+  fn add_token_counter(&self, keyword: &str, token: &AlkaneId, amount: u128) -> Result<u128> {
+    let total = self.token_counter(keyword, token).checked_add(amount)
+      .ok_or_else(|| anyhow!("{} overflow", keyword))?;
+    StoragePointer::from_keyword(keyword).select(&Self::alkane_id_to_bytes(token)).set_value(total);
+
+    Ok(total)
+  }
+
+  fn reveal_height_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/reveal-height")
+  }
+
+  fn reveal_height(&self) -> u128 {
+    self.reveal_height_pointer().get_value::<u128>()
+  }
+
+  fn font_mode_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/font-mode")
+  }
+
+  // Only SetFontMode writes this, so anything else is the web fonts default
+  fn font_mode(&self) -> FontMode {
+    FontMode::from_code(self.font_mode_pointer().get_value::<u128>()).unwrap_or_default()
+  }
+
+  fn reveal_seed_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/reveal-seed")
+  }
+
+  fn reveal_seed(&self) -> Option<Vec<u8>> {
+    let seed = self.reveal_seed_pointer().get();
+    if seed.is_empty() {
+      None
+    } else {
+      Some(seed.as_ref().clone())
+    }
+  }
+
+  // Zero reveal height serves real traits right after mint, otherwise cards wait for Reveal
+  fn is_revealed(&self) -> bool {
+    self.reveal_height() == 0 || self.reveal_seed().is_some()
+  }
+
+  // Card data is only served for minted orbitals, unminted rarity stays private
+  fn ensure_minted(&self, index: u128) -> Result<()> {
+    if index >= self.instances_count() {
+      return Err(anyhow!("Orbital {} is not minted yet", index));
+    }
+
+    Ok(())
+  }
+
+  fn instances_pointer(&self) -> StoragePointer {
+    StoragePointer::from_keyword("/instances")
+  }
+
+  fn instances_count(&self) -> u128 {
+    self.instances_pointer().get_value::<u128>()
+  }
+
+  fn set_instances_count(&self, count: u128) {
+    self.instances_pointer().set_value(count);
+  }
+
+  fn add_instance(&self, instance_id: &AlkaneId) -> Result<u128> {
+    let count = self.instances_count();
+    let new_count = count.checked_add(1)
+      .ok_or_else(|| anyhow!("instances count overflow"))?;
+    if new_count > self.max_supply() {
+      return Err(anyhow!("instances count exceeds max supply"));
+    }
+
+    let bytes = Self::alkane_id_to_bytes(instance_id);
+
+    let bytes_vec = new_count.to_le_bytes().to_vec();
+    let mut instance_pointer = self.instances_pointer().select(&bytes_vec);
+    instance_pointer.set(Arc::new(bytes));
+
+    // Reverse mapping keeps the same 1-based index, so zero means unknown
+    self.instance_index_pointer(instance_id).set_value(new_count);
+    
+    self.set_instances_count(new_count);
+    
+    Ok(new_count)
+  }
+
+
+  fn lookup_instance(&self, index: u128) -> Result<AlkaneId> {
+    // Add 1 to index since instances are stored at 1-based indices
+    let storage_index = index + 1;
     let bytes_vec = storage_index.to_le_bytes().to_vec();
     
     let instance_pointer = self.instances_pointer().select(&bytes_vec);
@@ -282,13 +1394,61 @@ impl MagicArbuzCollection {
       return Err(anyhow!("Invalid instance data length"));
     }
 
-    let block_bytes = &bytes[..16];
-    let tx_bytes = &bytes[16..];
+    Self::alkane_id_from_bytes(&bytes)
+  }
+
+  // Double SHA-256 of the current block header
+  fn block_hash(&self) -> Vec<u8> {
+    let block = self.block();
+    let header = &block[..block.len().min(80)];
+    Sha256::digest(Sha256::digest(header)).to_vec()
+  }
 
-    let block = u128::from_le_bytes(block_bytes.try_into().unwrap());
-    let tx = u128::from_le_bytes(tx_bytes.try_into().unwrap());
+  // Mint transaction is the commitment, the hash of the block that confirms it is the reveal,
+  // so nobody can know a card's traits before it is mined. It is stored in the open though, with
+  // a delayed reveal the reveal seed is what keeps traits hidden after the mint.
+  fn mint_entropy(block_hash: &[u8], transaction_digest: &[u8], index: u128) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(block_hash);
+    hasher.update(transaction_digest);
+    hasher.update(index.to_le_bytes());
+    hasher.finalize().to_vec()
+  }
 
-    Ok(AlkaneId { block, tx })
+  fn instance_entropy_pointer(&self, index: u128) -> StoragePointer {
+    // Same 1-based indices as instances
+    let storage_index = index + 1;
+    StoragePointer::from_keyword("/instance-entropy").select(&storage_index.to_le_bytes().to_vec())
+  }
+
+  fn instance_entropy(&self, index: u128) -> Vec<u8> {
+    self.instance_entropy_pointer(index).get().as_ref().clone()
+  }
+
+  fn set_instance_entropy(&self, index: u128, entropy: &[u8]) {
+    self.instance_entropy_pointer(index).set(Arc::new(entropy.to_vec()));
+  }
+
+  // What the generators derive a revealed card from, mint entropy followed by the reveal seed if any
+  fn card_entropy(&self, index: u128) -> Vec<u8> {
+    let mut entropy = self.instance_entropy(index);
+    if let Some(seed) = self.reveal_seed() {
+      entropy.extend_from_slice(&seed);
+    }
+    entropy
+  }
+
+  fn instance_index_pointer(&self, instance_id: &AlkaneId) -> StoragePointer {
+    StoragePointer::from_keyword("/instance-index").select(&Self::alkane_id_to_bytes(instance_id))
+  }
+
+  fn lookup_index(&self, instance_id: &AlkaneId) -> Result<u128> {
+    let storage_index = self.instance_index_pointer(instance_id).get_value::<u128>();
+    if storage_index == 0 {
+      return Err(anyhow!("{}:{} is not an orbital of this collection", instance_id.block, instance_id.tx));
+    }
+
+    Ok(storage_index - 1)
   }
 
   fn get_instance_alkane_id(&self, index: u128) -> Result<CallResponse> {
@@ -296,11 +1456,7 @@ impl MagicArbuzCollection {
     let mut response = CallResponse::forward(&context.incoming_alkanes);
 
     let instance_id = self.lookup_instance(index)?;
-    let mut bytes = Vec::with_capacity(32);
-    bytes.extend_from_slice(&instance_id.block.to_le_bytes());
-    bytes.extend_from_slice(&instance_id.tx.to_le_bytes());
-
-    response.data = bytes;
+    response.data = Self::alkane_id_to_bytes(&instance_id);
 
     Ok(response)
   }
@@ -314,6 +1470,39 @@ impl MagicArbuzCollection {
 
     Ok(response)
   }
+
+  // Header of (total minted, records in page) followed by (index, block, tx) records
+  fn get_instances(&self, offset: u128, limit: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let count = self.instances_count();
+    let start = offset.min(count);
+    let end = start.saturating_add(limit.min(MAX_INSTANCES_PAGE)).min(count);
+
+    let mut bytes = Vec::with_capacity(32 + ((end - start) as usize) * 48);
+    bytes.extend_from_slice(&count.to_le_bytes());
+    bytes.extend_from_slice(&(end - start).to_le_bytes());
+    for index in start..end {
+      let instance_id = self.lookup_instance(index)?;
+      bytes.extend_from_slice(&index.to_le_bytes());
+      bytes.extend_from_slice(&Self::alkane_id_to_bytes(&instance_id));
+    }
+
+    response.data = bytes;
+
+    Ok(response)
+  }
+
+  fn get_index_by_alkane_id(&self, block: u128, tx: u128) -> Result<CallResponse> {
+    let context = self.context()?;
+    let mut response = CallResponse::forward(&context.incoming_alkanes);
+
+    let index = self.lookup_index(&AlkaneId { block, tx })?;
+    response.data = index.to_le_bytes().to_vec();
+
+    Ok(response)
+  }
 }
 
 declare_alkane! {
@@ -1,7 +1,8 @@
 use std::string::String;
 use sha2::{Sha256, Digest};
 
-pub fn generate_prediction(index: u128) -> (String, String) {
+// `entropy` is captured per card at mint time, empty entropy hashes the index alone
+pub fn generate_prediction(index: u128, entropy: &[u8]) -> (String, String) {
     if index == 0 {
         return (
             "A JOURNEY OF A THOUSAND MILES BEGINS WITH A SINGLE STEP".to_string(),
@@ -105,7 +106,7 @@ pub fn generate_prediction(index: u128) -> (String, String) {
         ["Your Waiter", "你的服务员"],
         ["Your Barista", "你的咖啡师"],
         ["Your Therapist", "你的治疗师"],
-        ["Your Neighbor\\'s Dog", "你邻居的狗"],
+        ["Your Neighbor's Dog", "你邻居的狗"],
         ["Your Cat", "你的猫"],
         ["Your Dog", "你的狗"],
         ["Your Pet", "你的宠物"],
@@ -197,6 +198,7 @@ pub fn generate_prediction(index: u128) -> (String, String) {
 
     let mut hasher = Sha256::new();
     hasher.update(index.to_le_bytes());
+    hasher.update(entropy);
     let hash = hasher.finalize();
 
     let prediction_type = hash[24] % 2;
@@ -0,0 +1,175 @@
+use anyhow::{anyhow, Result};
+
+// Pricing policy modes, step is expressed in basis points of each payment token's base price
+pub const PRICING_FIXED: u128 = 0;
+pub const PRICING_LINEAR: u128 = 1;
+pub const PRICING_EXPONENTIAL: u128 = 2;
+
+pub const BASIS_POINTS: u128 = 10_000;
+// Fixed point scale of the exponential growth factor
+const GROWTH_PRECISION: u128 = 1_000_000_000;
+
+// Price charged for the orbital minted at `index`. The curve steps up once every `interval` mints,
+// `discount_bps` is the presale discount taken off the curve price, zero in the public phase.
+pub fn price_at(base_price: u128, mode: u128, step_bps: u128, interval: u128, index: u128, discount_bps: u128) -> Result<u128> {
+  let price = curve_price(base_price, mode, step_bps, interval, index)?;
+  if discount_bps > BASIS_POINTS {
+    return Err(anyhow!("Presale discount cannot exceed {} basis points", BASIS_POINTS));
+  }
+
+  let discount = price.checked_mul(discount_bps)
+    .ok_or_else(|| anyhow!("mint price overflow"))?
+    / BASIS_POINTS;
+
+  Ok(price - discount)
+}
+
+fn curve_price(base_price: u128, mode: u128, step_bps: u128, interval: u128, index: u128) -> Result<u128> {
+  if mode == PRICING_FIXED {
+    return Ok(base_price);
+  }
+  if mode > PRICING_EXPONENTIAL {
+    return Err(anyhow!("Unknown pricing mode {}", mode));
+  }
+  if interval == 0 {
+    return Err(anyhow!("Pricing interval must be greater than zero"));
+  }
+
+  let steps = index / interval;
+  let overflow = || anyhow!("mint price overflow");
+
+  if mode == PRICING_LINEAR {
+    let increase = base_price.checked_mul(step_bps).ok_or_else(overflow)?
+      .checked_mul(steps).ok_or_else(overflow)?
+      / BASIS_POINTS;
+    return base_price.checked_add(increase).ok_or_else(overflow);
+  }
+
+  // (1 + step)^steps by squaring, so the cost of a quote grows with log(steps) instead of supply
+  let mut growth = GROWTH_PRECISION;
+  let mut factor = BASIS_POINTS.checked_add(step_bps).ok_or_else(overflow)?
+    .checked_mul(GROWTH_PRECISION / BASIS_POINTS).ok_or_else(overflow)?;
+  let mut exponent = steps;
+  while exponent > 0 {
+    if exponent & 1 == 1 {
+      growth = growth.checked_mul(factor).ok_or_else(overflow)? / GROWTH_PRECISION;
+    }
+    exponent >>= 1;
+    if exponent > 0 {
+      factor = factor.checked_mul(factor).ok_or_else(overflow)? / GROWTH_PRECISION;
+    }
+  }
+
+  Ok(base_price.checked_mul(growth).ok_or_else(overflow)? / GROWTH_PRECISION)
+}
+
+// Locked and creator shares of a mint payment, rounded down, the treasury keeps the rest
+pub fn fee_shares(amount: u128, lock_bps: u128, creator_bps: u128) -> Result<(u128, u128)> {
+  let overflow = || anyhow!("fee split overflow");
+  if lock_bps.checked_add(creator_bps).ok_or_else(overflow)? > BASIS_POINTS {
+    return Err(anyhow!("Locked and creator shares cannot exceed {} basis points", BASIS_POINTS));
+  }
+
+  let locked = amount.checked_mul(lock_bps).ok_or_else(overflow)? / BASIS_POINTS;
+  let creator_share = amount.checked_mul(creator_bps).ok_or_else(overflow)? / BASIS_POINTS;
+
+  Ok((locked, creator_share))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const BASE: u128 = 10_000_000_000;
+
+  #[test]
+  fn fixed_price_ignores_the_curve() {
+    for index in [0, 1, 99, 10_000, u128::MAX] {
+      assert_eq!(price_at(BASE, PRICING_FIXED, 5_000, 0, index, 0).unwrap(), BASE);
+    }
+  }
+
+  #[test]
+  fn linear_price_steps_at_interval_boundaries() {
+    // +10% of the base price every 10 mints
+    let price = |index| price_at(BASE, PRICING_LINEAR, 1_000, 10, index, 0).unwrap();
+    assert_eq!(price(0), BASE);
+    assert_eq!(price(9), BASE);
+    assert_eq!(price(10), 11_000_000_000);
+    assert_eq!(price(19), 11_000_000_000);
+    assert_eq!(price(20), 12_000_000_000);
+    assert_eq!(price(1_000), 110_000_000_000);
+  }
+
+  #[test]
+  fn exponential_price_compounds_at_interval_boundaries() {
+    // x2 every 5 mints
+    let price = |index| price_at(BASE, PRICING_EXPONENTIAL, 10_000, 5, index, 0).unwrap();
+    assert_eq!(price(0), BASE);
+    assert_eq!(price(4), BASE);
+    assert_eq!(price(5), 2 * BASE);
+    assert_eq!(price(9), 2 * BASE);
+    assert_eq!(price(10), 4 * BASE);
+    assert_eq!(price(5 * 20), BASE << 20);
+
+    // +10% compounded: 1.1^3 = 1.331
+    assert_eq!(price_at(BASE, PRICING_EXPONENTIAL, 1_000, 1, 3, 0).unwrap(), 13_310_000_000);
+  }
+
+  #[test]
+  fn exponential_price_matches_repeated_multiplication() {
+    for steps in 0..40u128 {
+      let mut expected = BASE;
+      for _ in 0..steps {
+        expected = expected * 10_250 / BASIS_POINTS;
+      }
+      let quoted = price_at(BASE, PRICING_EXPONENTIAL, 250, 1, steps, 0).unwrap();
+      // The growth factor rounds down at GROWTH_PRECISION on every squaring
+      let tolerance = BASE / GROWTH_PRECISION * 2 * (steps + 1);
+      assert!(quoted.abs_diff(expected) <= tolerance, "{} steps: {} vs {}", steps, quoted, expected);
+    }
+  }
+
+  #[test]
+  fn presale_discount_comes_off_the_curve_price() {
+    assert_eq!(price_at(BASE, PRICING_FIXED, 0, 0, 0, 2_500).unwrap(), 7_500_000_000);
+    assert_eq!(price_at(BASE, PRICING_LINEAR, 1_000, 10, 10, 2_500).unwrap(), 8_250_000_000);
+    assert_eq!(price_at(BASE, PRICING_FIXED, 0, 0, 0, BASIS_POINTS).unwrap(), 0);
+    assert!(price_at(BASE, PRICING_FIXED, 0, 0, 0, BASIS_POINTS + 1).is_err());
+  }
+
+  #[test]
+  fn overflow_is_an_error() {
+    assert!(price_at(u128::MAX, PRICING_LINEAR, 1, 1, 1, 0).is_err());
+    assert!(price_at(BASE, PRICING_LINEAR, 1_000, 1, u128::MAX, 0).is_err());
+    assert!(price_at(BASE, PRICING_EXPONENTIAL, 10_000, 1, 200, 0).is_err());
+    assert!(price_at(u128::MAX, PRICING_EXPONENTIAL, 0, 1, 1, 0).is_err());
+    assert!(price_at(u128::MAX, PRICING_FIXED, 0, 0, 0, 2).is_err());
+  }
+
+  #[test]
+  fn curve_without_interval_is_an_error() {
+    assert!(price_at(BASE, PRICING_LINEAR, 1_000, 0, 5, 0).is_err());
+    assert!(price_at(BASE, PRICING_EXPONENTIAL, 1_000, 0, 5, 0).is_err());
+    assert!(price_at(BASE, 3, 1_000, 1, 5, 0).is_err());
+  }
+
+  #[test]
+  fn fee_shares_round_down_and_leave_the_rest_to_the_treasury() {
+    assert_eq!(fee_shares(BASE, 0, 0).unwrap(), (0, 0));
+    assert_eq!(fee_shares(BASE, 1_000, 500).unwrap(), (1_000_000_000, 500_000_000));
+    assert_eq!(fee_shares(BASE, BASIS_POINTS, 0).unwrap(), (BASE, 0));
+    assert_eq!(fee_shares(BASE, 0, BASIS_POINTS).unwrap(), (0, BASE));
+    // 3 * 3333 / 10000 rounds down to 0, dust stays in the treasury
+    assert_eq!(fee_shares(3, 3_333, 3_333).unwrap(), (0, 0));
+    assert_eq!(fee_shares(10_001, 5_000, 5_000).unwrap(), (5_000, 5_000));
+  }
+
+  #[test]
+  fn fee_shares_reject_overflow_and_more_than_the_payment() {
+    assert!(fee_shares(BASE, 6_000, 4_001).is_err());
+    assert!(fee_shares(BASE, u128::MAX, 1).is_err());
+    assert!(fee_shares(u128::MAX, 2, 0).is_err());
+    assert!(fee_shares(u128::MAX, 0, 2).is_err());
+  }
+}
@@ -0,0 +1,224 @@
+use crate::escape;
+use crate::js_generator::{CardFace, JsGenerator, Language, SYSTEM_CJK_FONTS};
+use crate::predict_generator::generate_prediction;
+use crate::template;
+use anyhow::Result;
+
+// Roughly what fits on one line of the message area at 20px, Chinese glyphs are about twice as wide
+const MESSAGE_LINE_CHARS: usize = 26;
+const MESSAGE_LINE_CHARS_CN: usize = 16;
+const MESSAGE_MAX_LINES: usize = 3;
+// Lines each language gets when both share the message area
+const MESSAGE_MAX_LINES_CN_BENEATH: usize = 2;
+const MESSAGE_LINE_HEIGHT: usize = 26;
+
+pub struct SvgGenerator;
+
+impl SvgGenerator {
+  // Splits on commas outside parentheses, so rgb(...) stops stay whole
+  fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+      match c {
+        '(' => depth += 1,
+        ')' => depth = depth.saturating_sub(1),
+        ',' if depth == 0 => {
+          parts.push(value[start..i].trim());
+          start = i + 1;
+        }
+        _ => {}
+      }
+    }
+    parts.push(value[start..].trim());
+    parts
+  }
+
+  // Turns a CSS `linear-gradient(<angle>deg, <color> <offset>, ...)` into an SVG <linearGradient>
+  fn linear_gradient(id: &str, css: &str) -> String {
+    let inner = css
+      .trim()
+      .strip_prefix("linear-gradient(")
+      .and_then(|rest| rest.strip_suffix(')'))
+      .unwrap_or("");
+    let mut parts = Self::split_top_level(inner);
+
+    let mut angle = 180.0f64;
+    if let Some(first) = parts.first() {
+      if let Some(degrees) = first.strip_suffix("deg").and_then(|d| d.trim().parse::<f64>().ok()) {
+        angle = degrees;
+        parts.remove(0);
+      }
+    }
+
+    // CSS angles point up at 0deg and turn clockwise, map the gradient line onto the bounding box
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let x1 = 50.0 - sin * 50.0;
+    let y1 = 50.0 + cos * 50.0;
+    let x2 = 50.0 + sin * 50.0;
+    let y2 = 50.0 - cos * 50.0;
+
+    let mut svg = format!(
+      "<linearGradient id=\"{}\" x1=\"{:.0}%\" y1=\"{:.0}%\" x2=\"{:.0}%\" y2=\"{:.0}%\">",
+      id, x1, y1, x2, y2
+    );
+    let count = parts.len();
+    for (i, stop) in parts.iter().enumerate() {
+      let (color, offset) = match stop.rsplit_once(' ') {
+        Some((color, offset)) if offset.ends_with('%') => (color.trim(), offset.to_string()),
+        _ => {
+          let offset = if count > 1 { i * 100 / (count - 1) } else { 0 };
+          (*stop, format!("{}%", offset))
+        }
+      };
+      svg.push_str(&format!(
+        "<stop offset=\"{}\" stop-color=\"{}\"/>",
+        escape::html(&offset),
+        escape::html(color)
+      ));
+    }
+    svg.push_str("</linearGradient>");
+    svg
+  }
+
+  // Greedy word wrap, the last line is cut with an ellipsis if the prediction runs long
+  fn wrap_message(message: &str, line_chars: usize, max_lines: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in message.split_whitespace() {
+      if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > line_chars {
+        lines.push(std::mem::take(&mut current));
+      }
+      if !current.is_empty() {
+        current.push(' ');
+      }
+      current.push_str(word);
+    }
+    if !current.is_empty() {
+      lines.push(current);
+    }
+    if lines.len() > max_lines {
+      lines.truncate(max_lines);
+      if let Some(last) = lines.last_mut() {
+        last.push('…');
+      }
+    }
+    lines
+  }
+
+  pub fn generate_svg(index: u128, entropy: &[u8], language: Language) -> Result<String> {
+    let face = CardFace::from_traits(&JsGenerator::decode_traits(index, entropy)?);
+    Self::render_svg(index, &face, generate_prediction(index, entropy), language)
+  }
+
+  pub fn generate_unrevealed_svg(index: u128, language: Language) -> Result<String> {
+    Self::render_svg(index, &CardFace::unrevealed(), JsGenerator::unrevealed_prediction(), language)
+  }
+
+  // Static rendering of the same 400x650 card `JsGenerator::generate_js` draws, front side only
+  pub(crate) fn render_svg(index: u128, face: &CardFace, predictions: (String, String), language: Language) -> Result<String> {
+    let (prediction_eng, prediction_cn) = predictions;
+
+    let index_display = JsGenerator::index_display(index);
+
+    let background_value = escape::css_value(face.background);
+    let main_symbol_value = face.main_symbol;
+    let border_color_value = escape::css_value(face.border_color);
+    let glow_color_value = escape::css_value(face.glow_color);
+
+    let (card_title_text, card_title_cn_value) = face.title_for(language);
+    let card_title_cn = match card_title_cn_value {
+      Some(title_cn) => template::render(template::TITLE_CN_SVG, &[
+        ("font", &escape::html(SYSTEM_CJK_FONTS)),
+        ("title", &escape::html(title_cn)),
+      ])?,
+      None => String::new(),
+    };
+
+    let mut mystical_symbols = String::new();
+    for (i, mystical_symbol_value) in face.mystical_symbols.iter().enumerate() {
+      mystical_symbols.push_str(&template::render(template::MYSTICAL_SYMBOL_SVG, &[
+        ("x", &(158 + i * 42).to_string()),
+        ("symbol", &escape::html(mystical_symbol_value)),
+      ])?);
+    }
+
+    // Prediction, centred vertically in the message area with the Chinese lines last
+    let (lines, lines_cn) = match language {
+      Language::English => (Self::wrap_message(&prediction_eng, MESSAGE_LINE_CHARS, MESSAGE_MAX_LINES), Vec::new()),
+      Language::Chinese => (Vec::new(), Self::wrap_message(&prediction_cn, MESSAGE_LINE_CHARS_CN, MESSAGE_MAX_LINES)),
+      Language::Both => (
+        Self::wrap_message(&prediction_eng, MESSAGE_LINE_CHARS, MESSAGE_MAX_LINES),
+        Self::wrap_message(&prediction_cn, MESSAGE_LINE_CHARS_CN, MESSAGE_MAX_LINES_CN_BENEATH),
+      ),
+    };
+    let line_count = lines.len() + lines_cn.len();
+    let first_line_y = 560 - (line_count.saturating_sub(1) * MESSAGE_LINE_HEIGHT) / 2;
+    let mut message_lines = String::new();
+    for (i, line) in lines.iter().enumerate() {
+      let dy = if i == 0 { 0 } else { MESSAGE_LINE_HEIGHT };
+      message_lines.push_str(&template::render(template::MESSAGE_LINE_SVG, &[
+        ("dy", &dy.to_string()),
+        ("line", &escape::html(line)),
+      ])?);
+    }
+    for (i, line) in lines_cn.iter().enumerate() {
+      let dy = if i == 0 && lines.is_empty() { 0 } else { MESSAGE_LINE_HEIGHT };
+      message_lines.push_str(&template::render(template::MESSAGE_LINE_CN_SVG, &[
+        ("dy", &dy.to_string()),
+        ("font", &escape::html(SYSTEM_CJK_FONTS)),
+        ("line", &escape::html(line)),
+      ])?);
+    }
+
+    let svg = template::render(template::CARD_SVG, &[
+      ("background_gradient", &Self::linear_gradient("card-background", &background_value)),
+      ("glow_color", &glow_color_value),
+      ("border_color", &border_color_value),
+      ("card_number", &escape::html(&index_display)),
+      ("card_title", &escape::html(card_title_text)),
+      ("card_title_cn", &card_title_cn),
+      ("main_symbol", &escape::html(main_symbol_value)),
+      ("mystical_symbols", &mystical_symbols),
+      ("message_y", &first_line_y.to_string()),
+      ("message_lines", &message_lines),
+    ])?;
+
+    Ok(svg)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::escape::tests::{markup_outline, ADVERSARIAL};
+  use crate::js_generator::tests::face_of;
+
+  // The prediction wraps to as many message lines as it needs, the rest of the outline is fixed
+  fn card_outline(svg: &str) -> String {
+    markup_outline(svg)
+      .replace("<tspan x=\"\" dy=\"\"></tspan>", "")
+      .replace("<tspan x=\"\" dy=\"\" font-family=\"\" font-size=\"\" letter-spacing=\"\"></tspan>", "")
+  }
+
+  #[test]
+  fn adversarial_text_stays_inside_svg_text_and_attributes() {
+    for language in [Language::English, Language::Chinese, Language::Both] {
+      let plain = SvgGenerator::render_svg(7, &face_of("plain"), ("plain".to_string(), "plain".to_string()), language).unwrap();
+      for value in ADVERSARIAL {
+        let predictions = (value.to_string(), value.to_string());
+        let svg = SvgGenerator::render_svg(7, &face_of(value), predictions, language).unwrap();
+        assert_eq!(card_outline(&svg), card_outline(&plain), "{:?} broke the SVG markup", value);
+        assert!(!svg.contains("<!--") && !svg.contains("<![CDATA["), "{:?} opened a comment or CDATA", value);
+        for (i, _) in svg.match_indices('&') {
+          let entity = &svg[i..];
+          assert!(
+            ["&amp;", "&lt;", "&gt;", "&quot;", "&#39;"].iter().any(|known| entity.starts_with(known)),
+            "{:?} left a bare & in the SVG", value
+          );
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,36 @@
+use anyhow::{anyhow, Result};
+
+// Card layouts live in src/templates and are embedded at compile time, like js-templates.json
+pub const CARD_JS: &str = include_str!("templates/card.js");
+pub const CARD_HTML: &str = include_str!("templates/card.html");
+pub const CARD_SVG: &str = include_str!("templates/card.svg");
+pub const BILINGUAL_TEXT_HTML: &str = include_str!("templates/bilingual-text.html");
+pub const FONT_IMPORT_CSS: &str = include_str!("templates/font-import.css");
+pub const MYSTICAL_SYMBOL_HTML: &str = include_str!("templates/mystical-symbol.html");
+pub const MYSTICAL_SYMBOL_SVG: &str = include_str!("templates/mystical-symbol.svg");
+pub const MESSAGE_LINE_SVG: &str = include_str!("templates/message-line.svg");
+pub const MESSAGE_LINE_CN_SVG: &str = include_str!("templates/message-line-cn.svg");
+pub const TITLE_CN_SVG: &str = include_str!("templates/title-cn.svg");
+
+// Replaces every `{{name}}` placeholder with its value. Values are inserted as they are, callers
+// escape them for the context the placeholder sits in. An unknown or unterminated placeholder is an
+// error so a typo in a template can't silently render a broken card.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String> {
+  let mut output = String::with_capacity(template.len() + 1024);
+  let mut rest = template;
+  while let Some(start) = rest.find("{{") {
+    output.push_str(&rest[..start]);
+    let after = &rest[start + 2..];
+    let end = after.find("}}").ok_or_else(|| anyhow!("unterminated template placeholder"))?;
+    let name = &after[..end];
+    let value = values
+      .iter()
+      .find(|(key, _)| *key == name)
+      .map(|(_, value)| *value)
+      .ok_or_else(|| anyhow!("unknown template placeholder {}", name))?;
+    output.push_str(value);
+    rest = &after[end + 2..];
+  }
+  output.push_str(rest);
+  Ok(output)
+}
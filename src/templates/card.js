@@ -0,0 +1,326 @@
+function createMagicArbuzCard(containerId) {
+  const container = document.getElementById(containerId);
+  if (!container) {
+    console.error('Container with id ' + containerId + ' not found');
+    return;
+  }
+
+  const cardData = {
+    title: '{{title_js}}',
+    title_cn: '{{title_cn_js}}',
+    isSpecialCard: {{is_special_card}},
+    subtitle: '{{card_number_js}}',
+    message_eng: '{{message_eng_js}}',
+    message_cn: '{{message_cn_js}}',
+    language: '{{language_js}}',
+    description: ''
+  };
+
+  const styles = `
+    <style>
+      {{font_import}}
+      @keyframes twinkle {
+        0% { opacity: 0.7; transform: scale(1); filter: drop-shadow(0 0 10px {{glow_color}}); }
+        50% { opacity: 1; transform: scale(1.05); filter: drop-shadow(0 0 20px {{glow_color}}); }
+        100% { opacity: 0.8; transform: scale(1.02); filter: drop-shadow(0 0 15px {{glow_color}}); }
+      }
+      @keyframes sparkle {
+        0% { opacity: 0.3; transform: scale(0.8); filter: drop-shadow(0 0 5px rgba(199, 210, 254, 0.4)); }
+        50% { opacity: 1; transform: scale(1.2); filter: drop-shadow(0 0 10px rgba(199, 210, 254, 0.8)); }
+        100% { opacity: 0.5; transform: scale(0.9); filter: drop-shadow(0 0 7px rgba(199, 210, 254, 0.6)); }
+      }
+      @keyframes glow {
+        0% { opacity: 0.6; transform: scale(1); filter: drop-shadow(0 0 5px currentColor); }
+        50% { opacity: 1; transform: scale(1.04); filter: drop-shadow(0 0 15px currentColor); }
+        100% { opacity: 0.7; transform: scale(1.02); filter: drop-shadow(0 0 10px currentColor); }
+      }
+      @keyframes cardGlow {
+        0% { box-shadow: inset 0 0 20px {{glow_color}}, 0 0 30px {{glow_color}}, 0 0 15px {{border_color}}; border-color: {{border_color}}; }
+        50% { box-shadow: inset 0 0 30px {{glow_color}}, 0 0 50px {{glow_color}}, 0 0 25px {{border_color}}; border-color: {{border_color}}; }
+        100% { box-shadow: inset 0 0 25px {{glow_color}}, 0 0 40px {{glow_color}}, 0 0 20px {{border_color}}; border-color: {{border_color}}; }
+      }
+      @keyframes backgroundShimmer {
+        0% { opacity: 0.3; background: radial-gradient(circle at 30% 30%, {{glow_color}} 0%, transparent 70%); }
+        25% { opacity: 0.6; background: radial-gradient(circle at 70% 40%, {{glow_color}} 0%, transparent 70%); }
+        50% { opacity: 0.8; background: radial-gradient(circle at 50% 70%, {{glow_color}} 0%, transparent 70%); }
+        75% { opacity: 0.4; background: radial-gradient(circle at 20% 60%, {{glow_color}} 0%, transparent 70%); }
+        100% { opacity: 0.5; background: radial-gradient(circle at 80% 20%, {{glow_color}} 0%, transparent 70%); }
+      }
+      .magic-arbuz-card-wrapper {
+        display: flex;
+        justify-content: center;
+        align-items: center;
+        perspective: 1000px;
+        min-height: 500px;
+      }
+      .magic-arbuz-card {
+        width: 400px;
+        height: 650px;
+        position: relative;
+        cursor: pointer;
+        transform-style: preserve-3d;
+        transition: all 0.1s ease-out;
+        transform: rotateY(0deg) rotateX(0deg) scale(1);
+        filter: drop-shadow(0 20px 40px rgba(0,0,0,0.5));
+        box-sizing: border-box;
+      }
+      .magic-arbuz-card-front {
+        position: absolute;
+        width: 100%;
+        height: 100%;
+        background: {{background}};
+        border-radius: 12px;
+        border: 3px solid {{border_color}};
+        box-shadow: inset 0 0 20px {{glow_color}}, 0 0 30px {{glow_color}};
+        animation: cardGlow 4s ease-in-out infinite alternate;
+        display: flex;
+        flex-direction: column;
+        justify-content: space-between;
+        padding: 25px;
+        backface-visibility: hidden;
+        position: relative;
+        overflow: hidden;
+        box-sizing: border-box;
+      }
+      .mystical-background {
+        position: absolute;
+        top: 0;
+        left: 0;
+        right: 0;
+        bottom: 0;
+        background: radial-gradient(circle at 50% 50%, {{glow_color}} 0%, transparent 70%);
+        opacity: 0.5;
+        animation: backgroundShimmer 5s ease-in-out infinite alternate;
+      }
+      .card-number {
+        text-align: center;
+        margin-bottom: 20px;
+      }
+      .card-number-text {
+        font-size: 28px;
+        font-weight: 700;
+        color: #ffd700;
+        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 1px 4px rgba(30,30,30,0.18),0 0 10px {{glow_color}};
+        font-family: serif;
+        letter-spacing: 2px;
+        animation: glow 3s ease-in-out infinite alternate;
+        animation-delay: 0.5s;
+        margin: 0;
+      }
+      .card-title-text {
+        font-size: 20px;
+        font-weight: 600;
+        color: #e0e7ff;
+        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 0 8px rgba(224,231,255,0.5);
+        font-family: serif;
+        letter-spacing: 1px;
+        margin-top: 5px;
+        animation: glow 3.5s ease-in-out infinite alternate;
+        animation-delay: 0.8s;
+        margin-bottom: 0;
+        min-height: 30px;
+        display: flex;
+        align-items: center;
+        justify-content: center;
+      }
+      .central-illustration {
+        display: flex;
+        flex-direction: column;
+        align-items: center;
+        justify-content: center;
+        flex: 1;
+        position: relative;
+      }
+      .main-symbol {
+        font-size: 80px;
+        color: {{border_color}};
+        text-shadow: 0 0 20px {{glow_color}};
+        animation: twinkle 4s ease-in-out infinite alternate;
+        z-index: 2;
+        position: relative;
+      }
+      .mystical-symbols {
+        display: flex;
+        justify-content: center;
+        gap: 10px;
+        margin-bottom: 10px;
+        margin-top: 0;
+      }
+      .mystical-symbol {
+        font-size: 32px;
+        text-shadow: 0 0 8px #a78bfa;
+        animation: glow 2.5s ease-in-out infinite alternate;
+      }
+      .small-stars-orbit{position:absolute;left:50%;top:50%;transform:translate(-50%,-50%);width:120px;height:120px;pointer-events:none;}.small-stars{font-size:24px;color:#c7d2fe;animation:sparkle 3s ease-in-out infinite alternate;z-index:1;pointer-events:none;}
+      .card-message {
+        text-align: center;
+        margin-top: 20px;
+      }
+      .card-message-text {
+        font-size: 20px;
+        font-weight: 700;
+        color: #cbd5e1;
+        font-style: normal;
+        line-height: 1.3;
+        font-family: {{latin_font}};
+        letter-spacing: 1px;
+        text-shadow: -0.5px -0.5px 0 #222, 0.5px -0.5px 0 #222, -0.5px 0.5px 0 #222, 0.5px 0.5px 0 #222, 0 1px 4px rgba(30,30,30,0.18),0 0 5px rgba(203, 213, 225, 0.3);
+        animation: glow 4s ease-in-out infinite alternate;
+        animation-delay: 1.2s;
+        margin: 0;
+        margin-bottom: 30px;
+        padding: 0 15px;
+        min-height: 60px;
+        height: 80px;
+        display: flex;
+        align-items: center;
+        justify-content: center;
+        text-align: center;
+        overflow: hidden;
+      }
+      .card-text-stack {
+        display: flex;
+        flex-direction: column;
+        align-items: center;
+        gap: 4px;
+      }
+      .card-text-cn {
+        font-family: {{cjk_font}};
+        font-size: 0.8em;
+        letter-spacing: 0.5px;
+      }
+      .card-message-text .card-text-stack {
+        font-size: 17px;
+        line-height: 1.2;
+      }
+      .magic-arbuz-card:hover {
+        transform: rotateY(5deg) rotateX(5deg) scale(1.02);
+        filter: drop-shadow(0 25px 50px rgba(0,0,0,0.6));
+      }
+      .magic-arbuz-card:active {
+        transform: rotateY(10deg) rotateX(10deg) scale(0.98);
+      }
+    </style>
+  `;
+
+  const html = `
+    <div class="magic-arbuz-card-wrapper">
+      <div class="magic-arbuz-card">
+        <div class="magic-arbuz-card-front">
+          <div class="magic-arbuz-card-border-decoration" style="pointer-events:none;position:absolute;top:0;left:0;width:100%;height:100%;z-index:1;"><svg viewBox='0 0 400 650' width='100%' height='100%' fill='none' xmlns='http://www.w3.org/2000/svg' style='display:block;'><rect x='14' y='12' width='372' height='626' rx='12' stroke='#ffe066' stroke-width='2.5' opacity='0.35'/></svg></div>
+          <div class="mystical-background"></div>
+          <div class="card-number">
+            <div class="card-number-text">{{card_number}}</div>
+            <div class="card-title-text">{{card_title}}</div>
+          </div>
+          <div class="central-illustration">
+            <div class="main-star-container" style="position:relative;margin-bottom:20px;">
+              <div class="main-star" style="font-size:140px;color:#ffd700;text-shadow:0 0 20px rgba(255,215,0,0.8);position:relative;z-index:2;filter:drop-shadow(0 0 10px rgba(255,215,0,0.5));animation:twinkle 2s ease-in-out infinite alternate;">{{main_symbol}}</div><div class="small-star small-star-1" style="position:absolute;top:-20px;left:-30px;font-size:30px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.2s;animation-duration:1.5s;">✦</div><div class="small-star small-star-2" style="position:absolute;top:-15px;right:-25px;font-size:25px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.5s;animation-duration:1.8s;">✦</div><div class="small-star small-star-3" style="position:absolute;bottom:-20px;left:-20px;font-size:20px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:0.8s;animation-duration:2.2s;">✦</div><div class="small-star small-star-4" style="position:absolute;bottom:-15px;right:-30px;font-size:35px;color:#c7d2fe;text-shadow:0 0 10px rgba(199,210,254,0.6);z-index:1;animation:sparkle 1.5s ease-in-out infinite alternate;animation-delay:1.1s;animation-duration:1.3s;">✦</div></div>
+<div class="mystical-symbols" style="display:flex;justify-content:center;gap:10px;margin-bottom:10px;margin-top:0;">{{mystical_symbols}}</div>
+</div>
+          <div class="card-message">
+            <div class="card-message-text">{{message}}</div>
+          </div>
+
+        </div>
+      </div>
+    </div>
+  `;
+
+  container.innerHTML = styles + html;
+
+  const magicArbuzCard = container.querySelector('.magic-arbuz-card');
+  if (magicArbuzCard) {
+    let lastRotateX = 0;
+    let lastRotateY = 0;
+    magicArbuzCard.addEventListener('mousemove', function(e) {
+      const rect = magicArbuzCard.getBoundingClientRect();
+      const x = e.clientX - rect.left;
+      const y = e.clientY - rect.top;
+      const centerX = rect.width / 2;
+      const centerY = rect.height / 2;
+      const deltaX = x - centerX; const deltaY = y - centerY; const distance = Math.sqrt(deltaX * deltaX + deltaY * deltaY); const maxDistance = Math.sqrt(centerX * centerX + centerY * centerY); const normalizedDistance = Math.min(distance / maxDistance, 1); const intensity = Math.pow(normalizedDistance, 0.6) * 1.2 + 0.4;
+      let rotateX = (deltaY / centerY) * 15 * intensity;
+      let rotateY = (-deltaX / centerX) * 15 * intensity;
+      const maxAngle = 15;
+      rotateX = Math.max(-maxAngle, Math.min(maxAngle, rotateX));
+      rotateY = Math.max(-maxAngle, Math.min(maxAngle, rotateY));
+      const smoothFactor = 0.15;
+      rotateX = lastRotateX + (rotateX - lastRotateX) * smoothFactor;
+      rotateY = lastRotateY + (rotateY - lastRotateY) * smoothFactor;
+      lastRotateX = rotateX; lastRotateY = rotateY;
+      magicArbuzCard.style.transform = 'rotateY(' + rotateY + 'deg) rotateX(' + rotateX + 'deg) scale(1.05)';
+      magicArbuzCard.style.filter = 'drop-shadow(0 30px 60px rgba(0,0,0,0.6))';
+    });
+    magicArbuzCard.addEventListener('mouseleave', function() {
+      lastRotateX = 0; lastRotateY = 0;
+      magicArbuzCard.style.transform = 'rotateY(0deg) rotateX(0deg) scale(1)';
+      magicArbuzCard.style.filter = 'drop-shadow(0 20px 40px rgba(0,0,0,0.5))';
+    });
+  }
+  
+  const messageDiv = container.querySelector('.card-message-text');
+  const titleDiv = container.querySelector('.card-title-text');
+  let currentLang = cardData.language === 'zh' ? 'cn' : 'eng';
+  let clickStartTime = 0;
+  let isLongPress = false;
+  let longPressTimer = null;
+  
+  function applyLang(lang) {
+    if (lang === 'cn') {
+      messageDiv.textContent = cardData.message_cn;
+      messageDiv.style.fontFamily = '{{cjk_font_js}}';
+      messageDiv.style.fontSize = '18px';
+      messageDiv.style.letterSpacing = '0.5px';
+      messageDiv.style.minHeight = '60px';
+      if (!cardData.isSpecialCard) {
+        titleDiv.textContent = cardData.title_cn;
+        titleDiv.style.fontFamily = '{{cjk_font_js}}';
+        titleDiv.style.fontSize = '18px';
+        titleDiv.style.letterSpacing = '0.5px';
+      }
+      titleDiv.style.minHeight = '30px';
+    } else {
+      messageDiv.textContent = cardData.message_eng;
+      messageDiv.style.fontFamily = '{{latin_font_js}}';
+      messageDiv.style.fontSize = '20px';
+      messageDiv.style.letterSpacing = '1px';
+      messageDiv.style.minHeight = '60px';
+      titleDiv.textContent = cardData.title;
+      titleDiv.style.fontFamily = 'serif';
+      titleDiv.style.fontSize = '20px';
+      titleDiv.style.letterSpacing = '1px';
+      titleDiv.style.minHeight = '30px';
+    }
+    currentLang = lang;
+  }
+  
+  // Both languages are already on the card, clicking only switches single-language cards
+  if (magicArbuzCard && messageDiv && titleDiv && cardData.language !== 'both') {
+    if (currentLang === 'cn') {
+      applyLang('cn');
+    }
+    
+    magicArbuzCard.addEventListener('mousedown', function() {
+      clickStartTime = Date.now();
+      isLongPress = false;
+      longPressTimer = setTimeout(function() {
+        isLongPress = true;
+      }, 300);
+    });
+    
+    magicArbuzCard.addEventListener('mouseup', function() {
+      clearTimeout(longPressTimer);
+      const clickDuration = Date.now() - clickStartTime;
+      if (clickDuration < 300 && !isLongPress) {
+        applyLang(currentLang === 'eng' ? 'cn' : 'eng');
+      }
+    });
+  }
+}
+
+if (typeof document !== 'undefined') {
+  document.addEventListener('DOMContentLoaded', function() {
+    createMagicArbuzCard('magic-arbuz-container');
+  });
+}
\ No newline at end of file
@@ -0,0 +1,48 @@
+use magic_arbuz::js_generator::{FontMode, JsGenerator, Language};
+use magic_arbuz::svg_generator::SvgGenerator;
+use std::fs;
+use std::path::PathBuf;
+
+// Existing cards as they render today, one of each tier plus a card minted with entropy. A template or
+// table edit that changes any of them fails here. After an intended change rerun with UPDATE_GOLDEN=1
+// to rewrite the files, and review the diff.
+const CARDS: &[(&str, u128, &[u8])] = &[
+  ("genesis-0", 0, &[]),
+  ("glitch-1", 1, &[]),
+  ("classic-2", 2, &[]),
+  ("absolute-281", 281, &[]),
+  ("minted-5", 5, &[0xab; 32]),
+];
+
+fn check(name: &str, rendered: String) {
+  let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+  if std::env::var_os("UPDATE_GOLDEN").is_some() {
+    fs::write(&path, rendered).unwrap();
+    return;
+  }
+
+  let expected = fs::read_to_string(&path).unwrap_or_else(|_| panic!("missing golden file {}", path.display()));
+  // Outputs run to tens of kilobytes, point at the first difference instead of printing both
+  if rendered != expected {
+    let at = rendered.bytes().zip(expected.bytes()).take_while(|(a, b)| a == b).count();
+    panic!("{} differs from its golden file at byte {}", name, at);
+  }
+}
+
+#[test]
+fn card_views_match_golden_output() {
+  for (name, index, entropy) in CARDS {
+    check(&format!("{}.js", name), JsGenerator::generate_js(*index, entropy, FontMode::WebFonts, Language::English).unwrap());
+    check(&format!("{}.html", name), JsGenerator::generate_html(*index, entropy, FontMode::WebFonts, Language::English).unwrap());
+    check(&format!("{}.svg", name), SvgGenerator::generate_svg(*index, entropy, Language::English).unwrap());
+    check(&format!("{}.attributes.json", name), JsGenerator::get_attributes(*index, entropy).unwrap());
+    check(&format!("{}.metadata.json", name), JsGenerator::get_metadata(*index, entropy, FontMode::WebFonts).unwrap());
+  }
+}
+
+#[test]
+fn unrevealed_card_views_match_golden_output() {
+  check("unrevealed-5.js", JsGenerator::generate_unrevealed_js(5, FontMode::WebFonts, Language::English).unwrap());
+  check("unrevealed-5.svg", SvgGenerator::generate_unrevealed_svg(5, Language::English).unwrap());
+  check("unrevealed-5.attributes.json", JsGenerator::get_unrevealed_attributes(5).unwrap());
+}